@@ -0,0 +1,102 @@
+//! Allen's interval algebra.
+
+use crate::impls::Item;
+use crate::ops::Operations;
+use crate::Interval;
+use std::cmp::Ordering;
+
+/// One of the 13 relations between two intervals in Allen's interval algebra.
+///
+/// Each variant describes the first interval relative to the second one.
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
+pub enum AllenRelation {
+    /// Ends before the other starts, with a gap between them.
+    Before,
+    /// Ends right where the other starts, without a gap or a common value.
+    Meets,
+    /// Starts first and ends inside the other.
+    Overlaps,
+    /// Starts with the other and ends first.
+    Starts,
+    /// Lies strictly inside the other.
+    During,
+    /// Ends with the other and starts last.
+    Finishes,
+    /// Has the same bounds as the other.
+    Equals,
+    /// Ends with the other and starts first.
+    FinishedBy,
+    /// Contains the other strictly inside.
+    Contains,
+    /// Starts with the other and ends last.
+    StartedBy,
+    /// Starts inside the other and ends last.
+    OverlappedBy,
+    /// Starts right where the other ends, without a gap or a common value.
+    MetBy,
+    /// Starts after the other ends, with a gap between them.
+    After,
+}
+
+impl AllenRelation {
+    /// Returns the relation of the second interval relative to the first one.
+    pub fn inverse(self) -> AllenRelation {
+        use AllenRelation::*;
+        match self {
+            Before => After,
+            Meets => MetBy,
+            Overlaps => OverlappedBy,
+            Starts => StartedBy,
+            During => Contains,
+            Finishes => FinishedBy,
+            Equals => Equals,
+            FinishedBy => Finishes,
+            Contains => During,
+            StartedBy => Starts,
+            OverlappedBy => Overlaps,
+            MetBy => Meets,
+            After => Before,
+        }
+    }
+}
+
+impl<T: Item> Interval<T> {
+    /// Classifies the interval relative to the other one, or `None` if either is empty.
+    ///
+    /// Open and closed bounds are taken into account, so `[1, 2)` meets `[2, 3]`,
+    /// while `[1, 2]` overlaps `[2, 3]` and `[1, 2)` is before `(2, 3]`.
+    pub fn relation(&self, other: &Interval<T>) -> Option<AllenRelation> {
+        use AllenRelation::*;
+        if self.empty() || other.empty() {
+            return None;
+        }
+
+        if self.adjacent(other) {
+            return Some(if self.upper == other.lower { Meets } else { MetBy });
+        }
+
+        if self.is_before(other) {
+            return Some(Before);
+        }
+
+        if self.is_after(other) {
+            return Some(After);
+        }
+
+        // The intervals overlap, compare their starts and ends.
+        let start = self.cmp_lower(other);
+        let end = (self.upper, self.right_closed()).cmp(&(other.upper, other.right_closed()));
+        let relation = match (start, end) {
+            (Ordering::Equal, Ordering::Equal) => Equals,
+            (Ordering::Equal, Ordering::Less) => Starts,
+            (Ordering::Equal, Ordering::Greater) => StartedBy,
+            (Ordering::Greater, Ordering::Equal) => Finishes,
+            (Ordering::Less, Ordering::Equal) => FinishedBy,
+            (Ordering::Greater, Ordering::Less) => During,
+            (Ordering::Less, Ordering::Greater) => Contains,
+            (Ordering::Less, Ordering::Less) => Overlaps,
+            (Ordering::Greater, Ordering::Greater) => OverlappedBy,
+        };
+        Some(relation)
+    }
+}
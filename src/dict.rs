@@ -0,0 +1,184 @@
+//! Holds the interval dictionary type and associated methods.
+
+use crate::helpers::find_containing;
+use crate::impls::Item;
+use crate::{Interval, IntervalSet};
+use std::fmt::Display;
+use std::iter::FromIterator;
+
+/// A mapping from intervals to values, like Python's `IntervalDict`.
+///
+/// Keys never overlap, a new key takes over the part of older keys it covers.
+/// Touching keys with equal values are merged.
+///
+/// ```
+/// use portion_rs::{IntervalDict, Portion};
+///
+/// let mut dict = IntervalDict::new();
+/// dict.insert(Portion::closed(2, 6), "a");
+/// dict.insert(Portion::closed(4, 8), "b");
+/// assert_eq!("{[2, 4): a, [4, 8]: b}", dict.to_string());
+/// assert_eq!(Some(&"a"), dict.get(&3));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntervalDict<T: Item, V> {
+    // Sorted by key, keys are atomic, disjoint and not empty.
+    entries: Vec<(Interval<T>, V)>,
+}
+
+impl<T: Item, V> IntervalDict<T, V> {
+    /// Creates an empty dictionary.
+    pub fn new() -> Self {
+        IntervalDict { entries: Vec::new() }
+    }
+
+    /// Returns the number of atomic keys.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the dictionary has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the value associated with the point.
+    pub fn get(&self, value: &T) -> Option<&V> {
+        find_containing(&self.entries, value, |(key, _)| key).map(|(_, v)| v)
+    }
+
+    /// Returns the union of all keys.
+    pub fn domain(&self) -> IntervalSet<T> {
+        self.entries.iter().map(|(key, _)| *key).collect()
+    }
+
+    /// Iterates over the atomic keys and their values, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (Interval<T>, &V)> {
+        self.entries.iter().map(|(key, value)| (*key, value))
+    }
+
+    // Gets the range of entries overlapping the atomic key, and also the ones touching it if `touching`.
+    fn range(&self, key: &Interval<T>, touching: bool) -> (usize, usize) {
+        let near = |x: &Interval<T>| x.overlaps(key) || (touching && x.adjacent(key));
+        let start = self.entries.partition_point(|(x, _)| x.is_before(key) && !near(x));
+        let end = start + self.entries[start..].iter().take_while(|(x, _)| near(x)).count();
+        (start, end)
+    }
+}
+
+impl<T: Item, V: Clone> IntervalDict<T, V> {
+    /// Removes every point of the key from the dictionary.
+    pub fn remove<K: Into<IntervalSet<T>>>(&mut self, key: K) {
+        self.remove_set(&key.into());
+    }
+
+    /// Returns the dictionary restricted to the points of the key.
+    pub fn get_interval<K: Into<IntervalSet<T>>>(&self, key: K) -> Self {
+        let mut entries = Vec::new();
+        for piece in key.into() {
+            let (start, end) = self.range(&piece, false);
+            for (interval, value) in &self.entries[start..end] {
+                entries.push((*interval & piece, value.clone()));
+            }
+        }
+        IntervalDict { entries }
+    }
+
+    fn remove_set(&mut self, key: &IntervalSet<T>) {
+        for piece in key.iter() {
+            let (start, end) = self.range(&piece, false);
+            let rest: Vec<_> = self
+                .entries
+                .drain(start..end)
+                .flat_map(|(interval, value)| (interval - piece).into_iter().map(move |x| (x, value.clone())))
+                .collect();
+            self.entries.splice(start..start, rest);
+        }
+    }
+}
+
+impl<T: Item, V: Clone + PartialEq> IntervalDict<T, V> {
+    /// Associates the value with every point of the key, replacing older values there.
+    pub fn insert<K: Into<IntervalSet<T>>>(&mut self, key: K, value: V) {
+        for piece in key.into() {
+            let (start, end) = self.range(&piece, true);
+            let mut merged = piece;
+            let mut entries = Vec::with_capacity(3);
+            for (interval, old) in self.entries.drain(start..end) {
+                if old == value {
+                    merged = merged.hull(&interval);
+                } else {
+                    for rest in interval - piece {
+                        entries.push((rest, old.clone()));
+                    }
+                }
+            }
+            entries.push((merged, value.clone()));
+            entries.sort_by_key(|(key, _)| *key);
+            self.entries.splice(start..start, entries);
+        }
+    }
+
+    /// Merges two dictionaries, using `how` to get the value where both have a key.
+    pub fn combine<F: Fn(&V, &V) -> V>(&self, other: &Self, how: F) -> Self {
+        let mut only_self = self.clone();
+        only_self.remove_set(&other.domain());
+        let mut only_other = other.clone();
+        only_other.remove_set(&self.domain());
+
+        let mut entries = only_self.entries;
+        entries.extend(only_other.entries);
+        for (y, y_value) in &other.entries {
+            let (start, end) = self.range(y, false);
+            for (x, x_value) in &self.entries[start..end] {
+                entries.push((*x & *y, how(x_value, y_value)));
+            }
+        }
+
+        // Entries are disjoint, so inserting them one by one only merges equal neighbours.
+        entries.sort_by_key(|(key, _)| *key);
+        let mut dict = IntervalDict::new();
+        for (key, value) in entries {
+            dict.insert(key, value);
+        }
+        dict
+    }
+}
+
+impl<T: Item, V> Default for IntervalDict<T, V> {
+    fn default() -> Self {
+        IntervalDict::new()
+    }
+}
+
+impl<T: Item, V: Clone + PartialEq, K: Into<IntervalSet<T>>> FromIterator<(K, V)> for IntervalDict<T, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut dict = IntervalDict::new();
+        for (key, value) in iter {
+            dict.insert(key, value);
+        }
+        dict
+    }
+}
+
+impl<T: Item, V> IntoIterator for IntervalDict<T, V> {
+    type Item = (Interval<T>, V);
+    type IntoIter = std::vec::IntoIter<(Interval<T>, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<T: Item + Display, V: Display> Display for IntervalDict<T, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (i, (key, value)) in self.entries.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {}", key, value)?;
+        }
+        write!(f, "}}")
+    }
+}
@@ -1,66 +1,37 @@
 // Helpers used in crate code.
 
-use crate::impls::Item;
+use crate::impls::{Discrete, Item, Numeric};
 use crate::interval::IntervalType::*;
-use crate::Interval;
+use crate::interval::{Bound, BoundKind};
+use crate::ops::Operations;
+use crate::{Interval, Portion};
+use std::cmp::Ordering;
 
 impl<T: Item> Interval<T> {
-    pub(crate) fn lower(&self) -> T {
-        self.lower.unwrap()
-    }
-
-    pub(crate) fn upper(&self) -> T {
-        self.upper.unwrap()
-    }
-
-    pub(crate) fn singleton(&self) -> bool {
-        self.itype == Singleton
-    }
-
-    pub(crate) fn left_open(&self) -> bool {
-        matches!(self.itype, Open | OpenClosed)
-    }
-
-    pub(crate) fn left_closed(&self) -> bool {
-        matches!(self.itype, Closed | ClosedOpen)
-    }
-
-    pub(crate) fn right_open(&self) -> bool {
-        matches!(self.itype, Open | ClosedOpen)
-    }
-
-    pub(crate) fn right_closed(&self) -> bool {
-        matches!(self.itype, Closed | OpenClosed)
-    }
-
-    pub(crate) fn contains(self, thing: T) -> bool {
-        self.into_iter().any(|x| x == thing)
-    }
-
     // Gets the common left point of two intervals.
     pub(crate) fn get_left_bound(&self, other: &Interval<T>) -> LeftBound<T> {
         if self.left_open() && other.left_open() {
             let val = (self.lower).max(other.lower);
-            return LeftBound::Open(val.unwrap());
+            return LeftBound::Open(val);
         }
 
         if self.left_closed() && other.left_closed() {
             let val = (self.lower).max(other.lower);
-            return LeftBound::Closed(val.unwrap());
+            return LeftBound::Closed(val);
         }
 
         if self.left_closed() && other.left_open() {
             if self.lower > other.lower {
-                return LeftBound::Closed(self.lower());
+                return LeftBound::Closed(self.lower);
             }
-            return LeftBound::Open(other.lower());
+            return LeftBound::Open(other.lower);
         }
 
         if self.left_open() && other.left_closed() {
             if other.lower > self.lower {
-                return LeftBound::Closed(other.lower());
+                return LeftBound::Closed(other.lower);
             }
-            return LeftBound::Open(self.lower());
+            return LeftBound::Open(self.lower);
         }
 
         LeftBound::None
@@ -70,26 +41,26 @@ impl<T: Item> Interval<T> {
     pub(crate) fn get_right_bound(&self, other: &Interval<T>) -> RightBound<T> {
         if self.right_open() && other.right_open() {
             let val = (self.upper).min(other.upper);
-            return RightBound::Open(val.unwrap());
+            return RightBound::Open(val);
         }
 
         if self.right_closed() && other.right_closed() {
             let val = (self.upper).min(other.upper);
-            return RightBound::Closed(val.unwrap());
+            return RightBound::Closed(val);
         }
 
         if self.right_closed() && other.right_open() {
             if self.upper < other.upper {
-                return RightBound::Open(self.upper());
+                return RightBound::Closed(self.upper);
             }
-            return RightBound::Closed(other.upper());
+            return RightBound::Open(other.upper);
         }
 
         if self.right_open() && other.right_closed() {
             if other.upper < self.upper {
-                return RightBound::Open(other.upper());
+                return RightBound::Closed(other.upper);
             }
-            return RightBound::Closed(self.upper());
+            return RightBound::Open(self.upper);
         }
 
         RightBound::None
@@ -99,51 +70,26 @@ impl<T: Item> Interval<T> {
     pub(crate) fn get_lowest_val(&self, other: &Interval<T>) -> LeftBound<T> {
         if self.left_open() && other.left_open() {
             let val = (self.lower).min(other.lower);
-            return LeftBound::Open(val.unwrap());
+            return LeftBound::Open(val);
         }
 
         if self.left_closed() && other.left_closed() {
             let val = (self.lower).min(other.lower);
-            return LeftBound::Closed(val.unwrap());
+            return LeftBound::Closed(val);
         }
 
         if self.left_closed() && other.left_open() {
-            if self.lower < other.lower {
-                return LeftBound::Closed(self.lower());
-            }
-            return LeftBound::Open(other.lower());
-        }
-
-        if self.left_open() && other.left_closed() {
-            if other.lower < self.lower {
-                return LeftBound::Closed(other.lower());
-            }
-            return LeftBound::Open(self.lower());
-        }
-
-        if self.singleton() && other.singleton() {
-            let val = (self.lower).min(other.lower);
-            return LeftBound::Closed(val.unwrap());
-        }
-
-        if self.singleton() {
             if self.lower <= other.lower {
-                return LeftBound::Closed(self.lower());
-            }
-            if other.left_closed() {
-                return LeftBound::Closed(other.lower());
+                return LeftBound::Closed(self.lower);
             }
-            return LeftBound::Open(other.lower());
+            return LeftBound::Open(other.lower);
         }
 
-        if other.singleton() {
+        if self.left_open() && other.left_closed() {
             if other.lower <= self.lower {
-                return LeftBound::Closed(other.lower());
+                return LeftBound::Closed(other.lower);
             }
-            if self.left_closed() {
-                return LeftBound::Closed(self.lower());
-            }
-            return LeftBound::Open(self.lower());
+            return LeftBound::Open(self.lower);
         }
 
         LeftBound::None
@@ -154,68 +100,252 @@ impl<T: Item> Interval<T> {
         // Both are open from the right.
         if self.right_open() && other.right_open() {
             let val = (self.upper).max(other.upper);
-            return RightBound::Open(val.unwrap());
+            return RightBound::Open(val);
         }
 
         // Both are closed from the right.
         if self.right_closed() && other.right_closed() {
             let val = (self.upper).max(other.upper);
-            return RightBound::Closed(val.unwrap());
+            return RightBound::Closed(val);
         }
 
         // Closed and open
         if self.right_closed() && other.right_open() {
             if self.upper >= other.upper {
-                return RightBound::Closed(self.upper());
+                return RightBound::Closed(self.upper);
             }
-            return RightBound::Open(other.upper());
+            return RightBound::Open(other.upper);
         }
 
         // Open and closed
         if self.right_open() && other.right_closed() {
             if self.upper > other.upper {
-                return RightBound::Open(self.upper());
+                return RightBound::Open(self.upper);
             }
-            return RightBound::Closed(other.upper());
+            return RightBound::Closed(other.upper);
         }
 
-        if self.singleton() && other.singleton() {
-            let val = (self.lower).max(other.lower);
-            return RightBound::Closed(val.unwrap());
+        RightBound::None
+    }
+
+    // Gets the points and kinds of the interval, the same for every empty interval.
+    pub(crate) fn normalized(&self) -> (Bound<T>, BoundKind, Bound<T>, BoundKind) {
+        if self.empty() {
+            return (Bound::PosInf, BoundKind::Open, Bound::NegInf, BoundKind::Open);
         }
+        let left = if matches!(self.itype, Closed | ClosedOpen | Singleton) {
+            BoundKind::Closed
+        } else {
+            BoundKind::Open
+        };
+        let right = if matches!(self.itype, Closed | OpenClosed | Singleton) {
+            BoundKind::Closed
+        } else {
+            BoundKind::Open
+        };
+        (self.lower, left, self.upper, right)
+    }
 
-        if self.singleton() {
-            if self.lower >= other.upper {
-                return RightBound::Closed(self.lower());
-            }
-            if other.right_closed() {
-                return RightBound::Closed(other.upper());
-            }
-            return RightBound::Open(other.upper());
+    // Orders two intervals by their left point, closed before open.
+    pub(crate) fn cmp_lower(&self, other: &Interval<T>) -> Ordering {
+        (self.lower, self.left_open()).cmp(&(other.lower, other.left_open()))
+    }
+
+    // Whether the interval starts no later than the other one ends, with a value in common at equal points.
+    pub(crate) fn starts_before_end(&self, other: &Interval<T>) -> bool {
+        self.lower < other.upper || (self.lower == other.upper && self.left_closed() && other.right_closed())
+    }
+
+    // Gets the smallest interval covering both intervals.
+    pub(crate) fn hull(&self, other: &Interval<T>) -> Interval<T> {
+        Interval::from_bounds(self.get_lowest_val(other), self.get_highest_val(other))
+    }
+
+    // Gets the right point of the values directly before the interval.
+    pub(crate) fn get_before_bound(&self) -> RightBound<T> {
+        if self.left_closed() {
+            return RightBound::Open(self.lower);
         }
+        RightBound::Closed(self.lower)
+    }
 
-        if other.singleton() {
-            if other.lower >= self.upper {
-                return RightBound::Closed(other.lower());
-            }
-            if self.right_closed() {
-                return RightBound::Closed(self.upper());
-            }
-            return RightBound::Open(self.upper());
+    // Gets the left point of the values directly after the interval.
+    pub(crate) fn get_after_bound(&self) -> LeftBound<T> {
+        if self.right_closed() {
+            return LeftBound::Open(self.upper);
         }
+        LeftBound::Closed(self.upper)
+    }
 
-        RightBound::None
+    // Builds an interval from its points, collapsing it to a singleton or empty interval if needed.
+    // Infinite points are always open.
+    pub(crate) fn from_bounds(left: LeftBound<T>, right: RightBound<T>) -> Interval<T> {
+        let left = match left {
+            LeftBound::Closed(lower) if lower.infinite() => LeftBound::Open(lower),
+            left => left,
+        };
+        let right = match right {
+            RightBound::Closed(upper) if upper.infinite() => RightBound::Open(upper),
+            right => right,
+        };
+
+        let (lower, upper, itype) = match (left, right) {
+            (LeftBound::Open(lower), RightBound::Open(upper)) => (lower, upper, Open),
+            (LeftBound::Open(lower), RightBound::Closed(upper)) => (lower, upper, OpenClosed),
+            (LeftBound::Closed(lower), RightBound::Open(upper)) => (lower, upper, ClosedOpen),
+            (LeftBound::Closed(lower), RightBound::Closed(upper)) => (lower, upper, Closed),
+            _ => return Portion::empty(),
+        };
+
+        let interval = Interval { lower, upper, itype };
+        if interval.empty() {
+            return Portion::empty();
+        }
+
+        if itype == Closed && lower == upper {
+            return Interval {
+                lower,
+                upper,
+                itype: Singleton,
+            };
+        }
+
+        interval
+    }
+}
+
+impl<T: Discrete> Interval<T> {
+    // Whether no value lies between two closed discrete intervals, assuming `self` comes first.
+    pub(crate) fn adjacent_discrete(&self, other: &Interval<T>) -> bool {
+        match (self.upper, other.lower) {
+            (Bound::Value(upper), Bound::Value(lower)) => upper.checked_next() == Some(lower),
+            _ => false,
+        }
+    }
+}
+
+impl<T: Numeric> Interval<T> {
+    // Builds an interval from computed points and whether they are included.
+    pub(crate) fn from_points(lower: (Bound<T>, bool), upper: (Bound<T>, bool)) -> Interval<T> {
+        let left = if lower.1 {
+            LeftBound::Closed(lower.0)
+        } else {
+            LeftBound::Open(lower.0)
+        };
+        let right = if upper.1 {
+            RightBound::Closed(upper.0)
+        } else {
+            RightBound::Open(upper.0)
+        };
+        Interval::from_bounds(left, right)
+    }
+}
+
+// Finds the entry whose interval contains the value, among entries sorted by disjoint intervals that may touch.
+pub(crate) fn find_containing<'a, T: Item, E>(
+    entries: &'a [E],
+    value: &T,
+    key: impl Fn(&E) -> &Interval<T>,
+) -> Option<&'a E> {
+    // Only the first interval not entirely below the value can contain it,
+    // one ending right before the value such as `[2, 4)` for `4` is below it.
+    let point = Bound::Value(*value);
+    let index = entries
+        .binary_search_by(|x| {
+            let x = key(x);
+            if x.upper < point || (x.upper == point && x.right_open()) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        })
+        .unwrap_err();
+    entries.get(index).filter(|x| key(x).contains(value))
+}
+
+// Gets how the point compares to zero.
+fn sign<T: Numeric>(x: Bound<T>) -> Ordering {
+    match x {
+        Bound::NegInf => Ordering::Less,
+        Bound::Value(x) => x.sign(),
+        Bound::PosInf => Ordering::Greater,
+    }
+}
+
+// Adds two points, going to infinity on overflow.
+pub(crate) fn add_points<T: Numeric>(x: Bound<T>, y: Bound<T>) -> Bound<T> {
+    match (x, y) {
+        (Bound::Value(x), Bound::Value(y)) => x.checked_add(y).map_or(
+            if y.sign().is_gt() { Bound::PosInf } else { Bound::NegInf },
+            Bound::Value,
+        ),
+        (Bound::Value(_), infinite) => infinite,
+        (infinite, _) => infinite,
+    }
+}
+
+// Applies an operation to two values, stopping at the limits of the type if it overflows.
+// Values past a limit are clamped to it, so the limit is reached and the side is closed.
+fn saturate<T: Numeric>(
+    x: T,
+    y: T,
+    closed: bool,
+    checked: fn(T, T) -> Option<T>,
+    saturating: fn(T, T) -> T,
+) -> (Bound<T>, bool) {
+    match checked(x, y) {
+        Some(value) => (Bound::Value(value), closed),
+        None => (Bound::Value(saturating(x, y)), true),
+    }
+}
+
+// Adds two sides of intervals, infinity absorbs any value.
+pub(crate) fn add_sides<T: Numeric>(
+    (x, x_closed): (Bound<T>, bool),
+    (y, y_closed): (Bound<T>, bool),
+) -> (Bound<T>, bool) {
+    let closed = x_closed && y_closed;
+    match (x, y) {
+        (Bound::Value(x), Bound::Value(y)) => saturate(x, y, closed, T::checked_add, T::saturating_add),
+        (Bound::Value(_), infinite) | (infinite, _) => (infinite, closed),
+    }
+}
+
+// Subtracts a value from a side of an interval.
+pub(crate) fn sub_side<T: Numeric>((x, closed): (Bound<T>, bool), y: T) -> (Bound<T>, bool) {
+    match x {
+        Bound::Value(x) => saturate(x, y, closed, T::checked_sub, T::saturating_sub),
+        infinite => (infinite, closed),
+    }
+}
+
+// Multiplies two sides of intervals, zero times infinity is zero.
+pub(crate) fn mul_sides<T: Numeric>(
+    (x, x_closed): (Bound<T>, bool),
+    (y, y_closed): (Bound<T>, bool),
+) -> (Bound<T>, bool) {
+    // A closed zero point times any value of the other interval is reached.
+    let closed = (x_closed && (y_closed || sign(x).is_eq())) || (y_closed && sign(y).is_eq());
+    let infinite = if sign(x) == sign(y) {
+        Bound::PosInf
+    } else {
+        Bound::NegInf
+    };
+    match (x, y) {
+        (Bound::Value(x), Bound::Value(y)) => saturate(x, y, closed, T::checked_mul, T::saturating_mul),
+        (Bound::Value(zero), _) | (_, Bound::Value(zero)) if zero.sign().is_eq() => (Bound::Value(zero), closed),
+        _ => (infinite, closed),
     }
 }
 
 pub(crate) enum LeftBound<T: Item> {
-    Open(T),
-    Closed(T),
+    Open(Bound<T>),
+    Closed(Bound<T>),
     None,
 }
 
 pub(crate) enum RightBound<T: Item> {
-    Open(T),
-    Closed(T),
+    Open(Bound<T>),
+    Closed(Bound<T>),
     None,
 }
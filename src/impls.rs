@@ -1,29 +1,92 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::fmt::Display;
+use std::str::FromStr;
 
-pub trait Item: Sized + Ord + Copy + Display {
-    fn next(self) -> Self {
-        unimplemented!()
+/// Values that can be used as interval bounds, implemented for every totally ordered type.
+pub trait Item: Sized + Ord + Copy {}
+
+impl<T: Ord + Copy> Item for T {}
+
+/// Values with a successor and a predecessor, required for iteration.
+///
+/// Continuous types such as floats do not implement it, so they cannot be iterated.
+///
+/// ```compile_fail
+/// use portion_rs::{OrderedFloat, Portion};
+///
+/// let x = Portion::closed(OrderedFloat(1.0), OrderedFloat(2.0));
+/// for value in x {}
+/// ```
+pub trait Discrete: Item {
+    /// Returns the value directly after this one, which may overflow at the largest value.
+    fn next(self) -> Self;
+
+    /// Returns the value directly before this one, which may overflow at the smallest value.
+    fn prev(self) -> Self;
+
+    /// Returns the smallest value of the type.
+    fn minimum() -> Self;
+
+    /// Returns the largest value of the type.
+    fn maximum() -> Self;
+
+    /// Returns the value directly after this one, or `None` at the largest value.
+    fn checked_next(self) -> Option<Self> {
+        if self >= Self::maximum() {
+            return None;
+        }
+        Some(self.next())
     }
 
-    fn next_ref(self) -> Self {
-        unimplemented!()
+    /// Returns the value directly before this one, or `None` at the smallest value.
+    fn checked_prev(self) -> Option<Self> {
+        if self <= Self::minimum() {
+            return None;
+        }
+        Some(self.prev())
     }
 
-    fn prev(self) -> Self {
-        unimplemented!()
+    /// Returns how many times `next` takes `start` to `end`, or `None` if it does not fit `usize`.
+    ///
+    /// The default implementation steps one value at a time, integers compute it directly.
+    fn steps_between(start: Self, end: Self) -> Option<usize> {
+        let mut steps: usize = 0;
+        let mut current = start;
+        while current < end {
+            current = current.next();
+            steps = steps.checked_add(1)?;
+        }
+        Some(steps)
     }
 
-    fn minimum(self) -> Self {
-        unimplemented!()
+    /// Returns the value `steps` times after this one, or `None` past the largest value.
+    ///
+    /// The default implementation steps one value at a time, integers compute it directly.
+    fn checked_forward(self, steps: usize) -> Option<Self> {
+        let mut current = self;
+        for _ in 0..steps {
+            current = current.checked_next()?;
+        }
+        Some(current)
     }
 
-    fn maximum(self) -> Self {
-        unimplemented!()
+    /// Returns the value `steps` times before this one, or `None` past the smallest value.
+    ///
+    /// The default implementation steps one value at a time, integers compute it directly.
+    fn checked_backward(self, steps: usize) -> Option<Self> {
+        let mut current = self;
+        for _ in 0..steps {
+            current = current.checked_prev()?;
+        }
+        Some(current)
     }
 }
 
+// The unsigned type of the same size holds the distance between any two values,
+// and wrapping arithmetic on it is exact once the result is known to fit.
 macro_rules! impl_num {
-    ($name:ident for $($t:ty)*) => ($(
+    ($name:ident for $($t:ty => $unsigned:ty)*) => ($(
         impl $name for $t {
             fn next(self) -> Self {
                 self + 1
@@ -33,15 +96,203 @@ macro_rules! impl_num {
                 self - 1
             }
 
-            fn minimum(self) -> Self {
+            fn minimum() -> Self {
                 <$t>::MIN
             }
 
-            fn maximum(self) -> Self {
+            fn maximum() -> Self {
                 <$t>::MAX
             }
+
+            fn checked_next(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn checked_prev(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn steps_between(start: Self, end: Self) -> Option<usize> {
+                if end <= start {
+                    return Some(0);
+                }
+                usize::try_from((end as $unsigned).wrapping_sub(start as $unsigned)).ok()
+            }
+
+            fn checked_forward(self, steps: usize) -> Option<Self> {
+                match Self::steps_between(self, <$t>::MAX) {
+                    Some(room) if room < steps => None,
+                    _ => Some((self as $unsigned).wrapping_add(steps as $unsigned) as $t),
+                }
+            }
+
+            fn checked_backward(self, steps: usize) -> Option<Self> {
+                match Self::steps_between(<$t>::MIN, self) {
+                    Some(room) if room < steps => None,
+                    _ => Some((self as $unsigned).wrapping_sub(steps as $unsigned) as $t),
+                }
+            }
+        }
+    )*)
+}
+
+impl_num!(Discrete for
+    usize => usize u8 => u8 u16 => u16 u32 => u32 u64 => u64 u128 => u128
+    isize => usize i8 => u8 i16 => u16 i32 => u32 i64 => u64 i128 => u128
+);
+
+/// Values supporting addition and multiplication, required for interval arithmetic.
+///
+/// The checked operations return `None` when the result does not fit the type,
+/// the saturating ones stop at the limits of the type instead.
+pub trait Numeric: Item {
+    /// Returns the zero value.
+    fn zero() -> Self;
+
+    /// Returns how the value compares to zero.
+    fn sign(self) -> Ordering;
+
+    /// Adds the values.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Subtracts the other value from this one.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// Multiplies the values.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// Adds the values, stopping at the limits of the type.
+    fn saturating_add(self, other: Self) -> Self;
+
+    /// Subtracts the other value from this one, stopping at the limits of the type.
+    fn saturating_sub(self, other: Self) -> Self;
+
+    /// Multiplies the values, stopping at the limits of the type.
+    fn saturating_mul(self, other: Self) -> Self;
+}
+
+macro_rules! impl_numeric {
+    ($($t:ty)*) => ($(
+        impl Numeric for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn sign(self) -> Ordering {
+                self.cmp(&0)
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn saturating_add(self, other: Self) -> Self {
+                <$t>::saturating_add(self, other)
+            }
+
+            fn saturating_sub(self, other: Self) -> Self {
+                <$t>::saturating_sub(self, other)
+            }
+
+            fn saturating_mul(self, other: Self) -> Self {
+                <$t>::saturating_mul(self, other)
+            }
+        }
+    )*)
+}
+
+impl_numeric!(usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128);
+
+/// A float with a total order, so it can be used as an interval bound.
+///
+/// Values are ordered by `total_cmp`, which puts `-0.0` before `0.0` and sorts `NaN` to the ends.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct OrderedFloat<F>(pub F);
+
+macro_rules! impl_float {
+    ($($t:ty)*) => ($(
+        impl PartialEq for OrderedFloat<$t> {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+
+        impl Eq for OrderedFloat<$t> {}
+
+        impl PartialOrd for OrderedFloat<$t> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for OrderedFloat<$t> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl Display for OrderedFloat<$t> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl FromStr for OrderedFloat<$t> {
+            type Err = <$t as FromStr>::Err;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse().map(OrderedFloat)
+            }
+        }
+
+        impl Numeric for OrderedFloat<$t> {
+            fn zero() -> Self {
+                OrderedFloat(0.0)
+            }
+
+            fn sign(self) -> Ordering {
+                self.0.partial_cmp(&0.0).unwrap_or(Ordering::Equal)
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                Some(OrderedFloat(self.0 + other.0))
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                Some(OrderedFloat(self.0 - other.0))
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                Some(OrderedFloat(self.0 * other.0))
+            }
+
+            fn saturating_add(self, other: Self) -> Self {
+                OrderedFloat(self.0 + other.0)
+            }
+
+            fn saturating_sub(self, other: Self) -> Self {
+                OrderedFloat(self.0 - other.0)
+            }
+
+            fn saturating_mul(self, other: Self) -> Self {
+                OrderedFloat(self.0 * other.0)
+            }
+        }
+
+        impl From<$t> for OrderedFloat<$t> {
+            fn from(value: $t) -> Self {
+                OrderedFloat(value)
+            }
         }
     )*)
 }
 
-impl_num!(Item for usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128);
+impl_float!(f32 f64);
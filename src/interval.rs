@@ -1,18 +1,99 @@
 //! Holds the interval type and associated methods.
 
-use crate::impls::Item;
-use crate::iter::IntoIter;
+use crate::helpers::{add_sides, mul_sides, LeftBound, RightBound};
+use crate::impls::{Discrete, Item, Numeric};
+use crate::iter::{IntoIter, Iterate};
 use crate::ops::Operations;
+use crate::{Notation, Portion};
+use std::cmp::Ordering;
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::iter::{Rev, StepBy};
 
 /// The interval type, main type of this library.
+#[derive(Clone, Copy, Debug)]
 pub struct Interval<T: Item> {
-    pub(crate) lower: Option<T>,
-    pub(crate) upper: Option<T>,
+    pub(crate) lower: Bound<T>,
+    pub(crate) upper: Bound<T>,
     pub(crate) itype: IntervalType,
 }
 
-#[derive(Eq, PartialEq, Copy, Clone)]
+/// A point of an interval, which can be infinite.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Debug)]
+pub enum Bound<T> {
+    /// Negative infinity, smaller than every value.
+    NegInf,
+    /// A finite value.
+    Value(T),
+    /// Positive infinity, larger than every value.
+    PosInf,
+}
+
+/// Whether a side of an interval includes its point.
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum BoundKind {
+    /// The point is excluded.
+    Open,
+    /// The point is included.
+    Closed,
+}
+
+/// The sides to change with [`Interval::replace`], a `None` field keeps the current side.
+///
+/// ```
+/// use portion_rs::{Bound, BoundKind, Portion, Replace};
+///
+/// let x = Portion::closedopen(1, 5).replace(Replace {
+///     upper: Some(Bound::Value(8)),
+///     right: Some(BoundKind::Closed),
+///     ..Replace::default()
+/// });
+/// assert_eq!("[1, 8]", x.to_string());
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Replace<T> {
+    /// The new kind of the left side.
+    pub left: Option<BoundKind>,
+    /// The new lower point.
+    pub lower: Option<Bound<T>>,
+    /// The new upper point.
+    pub upper: Option<Bound<T>>,
+    /// The new kind of the right side.
+    pub right: Option<BoundKind>,
+    /// Which of the changed sides are used.
+    pub mode: ReplaceMode,
+}
+
+impl<T> Default for Replace<T> {
+    fn default() -> Self {
+        Replace {
+            left: None,
+            lower: None,
+            upper: None,
+            right: None,
+            mode: ReplaceMode::Always,
+        }
+    }
+}
+
+/// Which changed sides [`Interval::replace`] uses.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub enum ReplaceMode {
+    /// Every changed side is used.
+    #[default]
+    Always,
+    /// A changed side is used only if it makes the interval larger.
+    Widen,
+    /// A changed side is used only if it makes the interval smaller.
+    Narrow,
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
 pub(crate) enum IntervalType {
     Open,
     Closed,
@@ -22,45 +103,486 @@ pub(crate) enum IntervalType {
     ClosedOpen,
 }
 
+impl<T: Item> Interval<T> {
+    /// Returns the lower point of the interval, `+inf` if the interval is empty.
+    pub fn lower_bound(&self) -> Bound<T> {
+        self.normalized().0
+    }
+
+    /// Returns the upper point of the interval, `-inf` if the interval is empty.
+    pub fn upper_bound(&self) -> Bound<T> {
+        self.normalized().2
+    }
+
+    /// Returns the kind of the left side, open if the interval is empty.
+    pub fn left(&self) -> BoundKind {
+        self.normalized().1
+    }
+
+    /// Returns the kind of the right side, open if the interval is empty.
+    pub fn right(&self) -> BoundKind {
+        self.normalized().3
+    }
+
+    /// Returns whether the interval is open from the left.
+    pub fn left_open(&self) -> bool {
+        self.left() == BoundKind::Open
+    }
+
+    /// Returns whether the interval is closed from the left.
+    pub fn left_closed(&self) -> bool {
+        self.left() == BoundKind::Closed
+    }
+
+    /// Returns whether the interval is open from the right.
+    pub fn right_open(&self) -> bool {
+        self.right() == BoundKind::Open
+    }
+
+    /// Returns whether the interval is closed from the right.
+    pub fn right_closed(&self) -> bool {
+        self.right() == BoundKind::Closed
+    }
+
+    /// Returns whether the interval contains exactly one value.
+    pub fn singleton(&self) -> bool {
+        let (lower, left, upper, right) = self.normalized();
+        lower == upper && left == BoundKind::Closed && right == BoundKind::Closed
+    }
+
+    /// Returns whether every value of the other interval is also in this interval.
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        if other.empty() {
+            return true;
+        }
+
+        if self.empty() {
+            return false;
+        }
+
+        let left = self.lower < other.lower || (self.lower == other.lower && (self.left_closed() || other.left_open()));
+        let right =
+            other.upper < self.upper || (other.upper == self.upper && (self.right_closed() || other.right_open()));
+        left && right
+    }
+
+    /// Returns whether the intervals share at least one value.
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        !self.empty() && !other.empty() && self.starts_before_end(other) && other.starts_before_end(self)
+    }
+
+    /// Returns whether the intervals touch without overlapping, such as `[1, 2)` and `[2, 3]`.
+    pub fn adjacent(&self, other: &Interval<T>) -> bool {
+        if self.empty() || other.empty() {
+            return false;
+        }
+
+        (self.upper == other.lower && self.right_closed() != other.left_closed())
+            || (other.upper == self.lower && other.right_closed() != self.left_closed())
+    }
+
+    /// Returns whether every value of the interval is lower than every value of the other one.
+    pub fn is_before(&self, other: &Interval<T>) -> bool {
+        !self.empty() && !other.empty() && !other.starts_before_end(self)
+    }
+
+    /// Returns whether every value of the interval is greater than every value of the other one.
+    pub fn is_after(&self, other: &Interval<T>) -> bool {
+        other.is_before(self)
+    }
+
+    /// Returns whether every value of the interval is below every value of the other one, like `I < J` in Python.
+    ///
+    /// Accepts a single value as well, and never holds if either side is empty, like `is_before`.
+    ///
+    /// ```
+    /// use portion_rs::Portion;
+    ///
+    /// assert!(Portion::closedopen(1, 2).entirely_left_of(2));
+    /// assert!(!Portion::closed(1, 2).entirely_left_of(Portion::closed(2, 3)));
+    /// ```
+    pub fn entirely_left_of<K: Into<Interval<T>>>(&self, other: K) -> bool {
+        self.is_before(&other.into())
+    }
+
+    /// Returns whether every value of the interval is above every value of the other one, like `I > J` in Python.
+    ///
+    /// Accepts a single value as well, and never holds if either side is empty, like `is_after`.
+    pub fn entirely_right_of<K: Into<Interval<T>>>(&self, other: K) -> bool {
+        other.into().entirely_left_of(*self)
+    }
+
+    /// Returns whether the interval does not extend to the right of the other one, like `I <= J` in Python.
+    ///
+    /// Accepts a single value as well, and never holds if either side is empty.
+    ///
+    /// ```
+    /// use portion_rs::Portion;
+    ///
+    /// assert!(Portion::closed(1, 3).left_of_or_overlapping(Portion::closed(2, 3)));
+    /// assert!(!Portion::closed(1, 3).left_of_or_overlapping(Portion::closedopen(2, 3)));
+    /// ```
+    pub fn left_of_or_overlapping<K: Into<Interval<T>>>(&self, other: K) -> bool {
+        let other = other.into();
+        if self.empty() || other.empty() {
+            return false;
+        }
+
+        (self.upper, self.right_closed()) <= (other.upper, other.right_closed())
+    }
+
+    /// Returns whether the interval does not extend to the left of the other one, like `I >= J` in Python.
+    ///
+    /// Accepts a single value as well, and never holds if either side is empty.
+    pub fn right_of_or_overlapping<K: Into<Interval<T>>>(&self, other: K) -> bool {
+        let other = other.into();
+        if self.empty() || other.empty() {
+            return false;
+        }
+
+        self.cmp_lower(&other) != Ordering::Less
+    }
+
+    /// Returns the interval with some of its sides changed, like `replace` in Python.
+    ///
+    /// Infinite sides are always open, and the result is empty if its points are out of order.
+    ///
+    /// ```
+    /// use portion_rs::{Bound, Portion, Replace, ReplaceMode};
+    ///
+    /// let widen = Replace {
+    ///     lower: Some(Bound::Value(2)),
+    ///     mode: ReplaceMode::Widen,
+    ///     ..Replace::default()
+    /// };
+    /// assert_eq!("[1, 5]", Portion::closed(1, 5).replace(widen).to_string());
+    /// assert_eq!("[2, 5]", Portion::closed(3, 5).replace(widen).to_string());
+    /// ```
+    pub fn replace(&self, replace: Replace<T>) -> Interval<T> {
+        // A side reaching further out makes the interval larger.
+        // Empty intervals all start from the same normalized sides.
+        let (lower, left, upper, right) = self.normalized();
+        let current_left = (lower, left == BoundKind::Open);
+        let current_right = (upper, right == BoundKind::Closed);
+        let left = (
+            replace.lower.unwrap_or(current_left.0),
+            replace.left.map_or(current_left.1, |x| x == BoundKind::Open),
+        );
+        let right = (
+            replace.upper.unwrap_or(current_right.0),
+            replace.right.map_or(current_right.1, |x| x == BoundKind::Closed),
+        );
+
+        let (lower, left_open) = match replace.mode {
+            ReplaceMode::Widen if left >= current_left => current_left,
+            ReplaceMode::Narrow if left <= current_left => current_left,
+            _ => left,
+        };
+        let (upper, right_closed) = match replace.mode {
+            ReplaceMode::Widen if right <= current_right => current_right,
+            ReplaceMode::Narrow if right >= current_right => current_right,
+            _ => right,
+        };
+
+        let left = if left_open {
+            LeftBound::Open(lower)
+        } else {
+            LeftBound::Closed(lower)
+        };
+        let right = if right_closed {
+            RightBound::Closed(upper)
+        } else {
+            RightBound::Open(upper)
+        };
+        Interval::from_bounds(left, right)
+    }
+
+    /// Iterates over the values of the interval, starting from the lower point and applying `step`.
+    ///
+    /// Works with continuous types as well, call `base` on the iterator to align the first value.
+    /// Yields nothing if the interval is unbounded from the left, as there is no value to start from.
+    ///
+    /// ```
+    /// use portion_rs::{OrderedFloat, Portion};
+    ///
+    /// let x = Portion::openclosed(OrderedFloat(0.2f64), OrderedFloat(1.0));
+    /// let values: Vec<f64> = x
+    ///     .iterate(|x| OrderedFloat(x.0 + 0.25))
+    ///     .base(|x| OrderedFloat(x.0.floor()))
+    ///     .map(|x| x.0)
+    ///     .collect();
+    /// assert_eq!(values, vec![0.25, 0.5, 0.75, 1.0]);
+    /// ```
+    pub fn iterate<F: Fn(T) -> T>(&self, step: F) -> Iterate<T, F> {
+        Iterate::new(vec![*self], step, false)
+    }
+
+    /// Iterates over the values of the interval, starting from the upper point and applying `step`.
+    ///
+    /// Yields nothing if the interval is unbounded from the right, as there is no value to start from.
+    pub fn iterate_rev<F: Fn(T) -> T>(&self, step: F) -> Iterate<T, F> {
+        Iterate::new(vec![*self], step, true)
+    }
+
+    /// Returns whether every value of the interval is also in the other one.
+    pub fn is_subset(&self, other: &Interval<T>) -> bool {
+        other.contains_interval(self)
+    }
+
+    /// Returns whether every value of the other interval is also in this one.
+    pub fn is_superset(&self, other: &Interval<T>) -> bool {
+        self.contains_interval(other)
+    }
+}
+
+impl<T: Discrete> Interval<T> {
+    /// Iterates over the values of the interval without consuming it.
+    ///
+    /// ```
+    /// use portion_rs::Portion;
+    ///
+    /// let x = Portion::closedopen(1, 4);
+    /// assert_eq!(vec![1, 2, 3], x.iter().collect::<Vec<_>>());
+    /// assert_eq!(vec![3, 2, 1], x.iter().rev().collect::<Vec<_>>());
+    /// ```
+    pub fn iter(&self) -> IntoIter<T> {
+        self.into_iter()
+    }
+
+    /// Iterates over the values of the interval from the upper end down.
+    pub fn rev(&self) -> Rev<IntoIter<T>> {
+        self.iter().rev()
+    }
+
+    /// Iterates over every `step`-th value of the interval, starting from the lowest one.
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// ```
+    /// use portion_rs::Portion;
+    ///
+    /// let x: Vec<u32> = Portion::closedopen(0, 20).iter_step(5).collect();
+    /// assert_eq!(vec![0, 5, 10, 15], x);
+    /// ```
+    pub fn iter_step(&self, step: usize) -> StepBy<IntoIter<T>> {
+        self.iter().step_by(step)
+    }
+
+    /// Returns the interval in its canonical closed form, treating values as discrete.
+    ///
+    /// For example `(1, 5)` becomes `[2, 4]`, which contains the same integers.
+    /// Infinite sides become the smallest or largest value of the type.
+    pub fn discrete(self) -> Interval<T> {
+        if self.empty() {
+            return Portion::empty();
+        }
+
+        let left = match self.lower {
+            Bound::Value(lower) if self.left_open() => match lower.checked_next() {
+                Some(lower) => LeftBound::Closed(Bound::Value(lower)),
+                None => return Portion::empty(),
+            },
+            Bound::NegInf => LeftBound::Closed(Bound::Value(T::minimum())),
+            lower => LeftBound::Closed(lower),
+        };
+
+        let right = match self.upper {
+            Bound::Value(upper) if self.right_open() => match upper.checked_prev() {
+                Some(upper) => RightBound::Closed(Bound::Value(upper)),
+                None => return Portion::empty(),
+            },
+            Bound::PosInf => RightBound::Closed(Bound::Value(T::maximum())),
+            upper => RightBound::Closed(upper),
+        };
+
+        Interval::from_bounds(left, right)
+    }
+
+    /// Returns the number of values in the interval, treating values as discrete.
+    ///
+    /// Unbounded sides stop at the limits of the type, like in `discrete`.
+    /// Returns `None` if the number does not fit `usize`.
+    ///
+    /// ```
+    /// use portion_rs::Portion;
+    ///
+    /// assert_eq!(Some(3), Portion::openclosed(1, 4).count());
+    /// assert_eq!(Some(256), Portion::<u8>::all().count());
+    /// ```
+    pub fn count(&self) -> Option<usize> {
+        let closed = self.discrete();
+        match (closed.lower, closed.upper) {
+            (Bound::Value(lower), Bound::Value(upper)) if !closed.empty() => {
+                T::steps_between(lower, upper)?.checked_add(1)
+            }
+            _ => Some(0),
+        }
+    }
+}
+
+impl<T: Numeric> Interval<T> {
+    /// Returns the distance between the lower and upper points, ignoring the bound kinds.
+    ///
+    /// It is `+inf` for unbounded intervals or when the distance does not fit the type, and zero when empty.
+    ///
+    /// ```
+    /// use portion_rs::{Bound, OrderedFloat, Portion};
+    ///
+    /// let x = Portion::closedopen(OrderedFloat(0.5), OrderedFloat(2.0));
+    /// assert_eq!(Bound::Value(OrderedFloat(1.5)), x.length());
+    /// ```
+    pub fn length(&self) -> Bound<T> {
+        match (self.lower, self.upper) {
+            _ if self.empty() => Bound::Value(T::zero()),
+            (Bound::Value(lower), Bound::Value(upper)) => upper.checked_sub(lower).map_or(Bound::PosInf, Bound::Value),
+            _ => Bound::PosInf,
+        }
+    }
+
+    /// Returns the interval of every sum of a value from each interval, as in interval arithmetic.
+    ///
+    /// A side past the limits of the type stops at them.
+    ///
+    /// ```
+    /// use portion_rs::Portion;
+    ///
+    /// assert_eq!("[4, 6)", Portion::closedopen(1, 2).sum(&Portion::closed(3, 4)).to_string());
+    /// assert_eq!("[250, 255]", Portion::closed(250u8, 255).sum(&Portion::closed(0, 10)).to_string());
+    /// ```
+    pub fn sum(&self, other: &Interval<T>) -> Interval<T> {
+        if self.empty() || other.empty() {
+            return Portion::empty();
+        }
+
+        Interval::from_points(
+            add_sides((self.lower, self.left_closed()), (other.lower, other.left_closed())),
+            add_sides((self.upper, self.right_closed()), (other.upper, other.right_closed())),
+        )
+    }
+
+    /// Returns the interval of every product of a value from each interval, as in interval arithmetic.
+    ///
+    /// A side past the limits of the type stops at them.
+    ///
+    /// ```
+    /// use portion_rs::Portion;
+    ///
+    /// assert_eq!("[-8, 8]", Portion::closed(-2, 2).product(&Portion::closed(-3, 4)).to_string());
+    /// ```
+    pub fn product(&self, other: &Interval<T>) -> Interval<T> {
+        if self.empty() || other.empty() {
+            return Portion::empty();
+        }
+
+        // The extremes are products of the points.
+        let sides = |x: &Interval<T>| [(x.lower, x.left_closed()), (x.upper, x.right_closed())];
+        let mut lower = (Bound::PosInf, false);
+        let mut upper = (Bound::NegInf, false);
+        for x in sides(self) {
+            for y in sides(other) {
+                let (point, closed) = mul_sides(x, y);
+                if (point, !closed) < (lower.0, !lower.1) {
+                    lower = (point, closed);
+                }
+                if (point, closed) > upper {
+                    upper = (point, closed);
+                }
+            }
+        }
+        Interval::from_points(lower, upper)
+    }
+}
+
+impl<T> Bound<T> {
+    /// Returns the finite value of the point, if any.
+    pub fn value(self) -> Option<T> {
+        match self {
+            Bound::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the point is infinite.
+    pub fn infinite(&self) -> bool {
+        !matches!(self, Bound::Value(_))
+    }
+}
+
 impl<T: Item> PartialEq for Interval<T> {
     fn eq(&self, other: &Self) -> bool {
-        self.to_string() == other.to_string()
+        self.normalized() == other.normalized()
     }
 }
 
 impl<T: Item> Eq for Interval<T> {}
 
-impl<T: Item> Display for Interval<T> {
+impl<T: Item + Hash> Hash for Interval<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized().hash(state);
+    }
+}
+
+impl<T: Item> From<T> for Interval<T> {
+    fn from(value: T) -> Self {
+        Portion::singleton(value)
+    }
+}
+
+impl<T: Item> PartialOrd for Interval<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Orders by the lower point, then by the upper point, with a closed lower point first and a closed upper point last.
+impl<T: Item> Ord for Interval<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (lower, left, upper, right) = self.normalized();
+        let (other_lower, other_left, other_upper, other_right) = other.normalized();
+        lower
+            .cmp(&other_lower)
+            .then((left == BoundKind::Open).cmp(&(other_left == BoundKind::Open)))
+            .then(upper.cmp(&other_upper))
+            .then((right == BoundKind::Closed).cmp(&(other_right == BoundKind::Closed)))
+    }
+}
+
+impl<T: Display> Display for Bound<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.empty() {
-            return write!(f, "()");
-        };
-        use IntervalType::*;
-        match self.itype {
-            Open => write!(f, "({}, {})", self.lower(), self.upper()),
-            Closed => write!(f, "[{}, {}]", self.lower(), self.upper()),
-            Empty => write!(f, "()"),
-            Singleton => write!(f, "[{}]", self.lower()),
-            OpenClosed => write!(f, "({}, {}]", self.lower(), self.upper()),
-            ClosedOpen => write!(f, "[{}, {})", self.lower(), self.upper()),
+        match self {
+            Bound::NegInf => write!(f, "-inf"),
+            Bound::Value(value) => write!(f, "{}", value),
+            Bound::PosInf => write!(f, "+inf"),
         }
     }
 }
 
-impl<T: Item> IntoIterator for Interval<T> {
+impl<T: Item + Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Notation::DEFAULT.write_interval(f, self)
+    }
+}
+
+impl<T: Discrete> IntoIterator for Interval<T> {
     type Item = T;
     type IntoIter = IntoIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        use IntervalType::*;
-        let current = match self.itype {
-            Open | OpenClosed => Some(self.lower().next()),
-            _ => self.lower,
+        let closed = self.discrete();
+        let range = match (closed.lower, closed.upper) {
+            (Bound::Value(lower), Bound::Value(upper)) if !closed.empty() => Some((lower, upper)),
+            _ => None,
         };
 
-        IntoIter {
-            current,
-            interval: self,
-        }
+        IntoIter { range }
+    }
+}
+
+impl<T: Discrete> IntoIterator for &Interval<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
     }
 }
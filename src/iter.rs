@@ -1,32 +1,174 @@
-use crate::{Interval, IntervalType, Item};
+use crate::impls::Item;
+use crate::interval::Bound;
+use crate::ops::Operations;
+use crate::{Discrete, Interval};
+use std::iter::{Copied, Flatten, FusedIterator};
+use std::{slice, vec};
 
-/// Iterator over values of the interval.
-pub struct IntoIter<T: Item> {
-    pub(crate) interval: Interval<T>,
-    pub(crate) current: Option<T>,
+/// Iterator over values of the interval, from either end.
+pub struct IntoIter<T: Discrete> {
+    // The first and last values left, `None` once exhausted.
+    pub(crate) range: Option<(T, T)>,
 }
 
-impl<T: Item> Iterator for IntoIter<T> {
+impl<T: Discrete> Iterator for IntoIter<T> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // Handle trivial cases
-        match self.interval.itype {
-            IntervalType::Empty => return None,
-            IntervalType::Singleton => return self.current.take(),
-            _ => {}
+        let (front, back) = self.range?;
+        // Never steps past the last value, so it stops at the largest value of the type.
+        self.range = if front < back {
+            front.checked_next().map(|next| (next, back))
+        } else {
+            None
+        };
+        Some(front)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (front, back) = match self.range {
+            Some(range) => range,
+            None => return (0, Some(0)),
+        };
+
+        match T::steps_between(front, back).and_then(|steps| steps.checked_add(1)) {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (front, back) = self.range?;
+        match T::steps_between(front, back) {
+            Some(steps) if steps < n => self.range = None,
+            _ => self.range = front.checked_forward(n).map(|front| (front, back)),
         }
+        self.next()
+    }
+}
+
+impl<T: Discrete> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (front, back) = self.range?;
+        self.range = if front < back {
+            back.checked_prev().map(|prev| (front, prev))
+        } else {
+            None
+        };
+        Some(back)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (front, back) = self.range?;
+        match T::steps_between(front, back) {
+            Some(steps) if steps < n => self.range = None,
+            _ => self.range = back.checked_backward(n).map(|back| (front, back)),
+        }
+        self.next_back()
+    }
+}
+
+// Only types whose every value can be counted in `usize`, like `RangeInclusive` in std.
+macro_rules! impl_exact_size {
+    ($($t:ty)*) => ($(
+        impl ExactSizeIterator for IntoIter<$t> {}
+    )*)
+}
+
+impl_exact_size!(u8 u16 i8 i16);
+
+impl<T: Discrete> FusedIterator for IntoIter<T> {}
+
+/// Iterator over values of every interval of a set.
+pub struct Values<'a, T: Discrete> {
+    pub(crate) inner: Flatten<Copied<slice::Iter<'a, Interval<T>>>>,
+}
+
+impl<T: Discrete> Iterator for Values<'_, T> {
+    type Item = T;
 
-        if self.interval.right_open() && self.current >= self.interval.upper {
-            return None;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T: Discrete> DoubleEndedIterator for Values<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T: Discrete> FusedIterator for Values<'_, T> {}
+
+/// Iterator over values of intervals, produced by a step function.
+pub struct Iterate<T: Item, F> {
+    pub(crate) pieces: vec::IntoIter<Interval<T>>,
+    pub(crate) piece: Option<Interval<T>>,
+    pub(crate) current: Option<T>,
+    pub(crate) step: F,
+    pub(crate) reverse: bool,
+}
+
+impl<T: Item, F: Fn(T) -> T> Iterate<T, F> {
+    // Starts at the lowest point of the pieces, or the highest one if reversed.
+    // An infinite starting point leaves nothing to step from, so the iterator is empty.
+    pub(crate) fn new(mut pieces: Vec<Interval<T>>, step: F, reverse: bool) -> Self {
+        pieces.retain(|x| !x.empty());
+        if reverse {
+            pieces.reverse();
         }
 
-        if self.interval.right_closed() && self.current > self.interval.upper {
-            return None;
+        let current = pieces
+            .first()
+            .and_then(|x| if reverse { x.upper } else { x.lower }.value());
+
+        let mut pieces = pieces.into_iter();
+        Iterate {
+            piece: pieces.next(),
+            pieces,
+            current,
+            step,
+            reverse,
         }
+    }
+
+    /// Aligns the first value with the function, such as rounding it down to a whole unit.
+    ///
+    /// Values produced before the first interval starts are skipped.
+    pub fn base<B: FnOnce(T) -> T>(mut self, base: B) -> Self {
+        self.current = self.current.map(base);
+        self
+    }
+}
 
-        let value = self.current;
-        self.current = Some(self.current.unwrap().next());
-        value
+impl<T: Item, F: Fn(T) -> T> Iterator for Iterate<T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let piece = self.piece?;
+            let value = self.current?;
+            if piece.contains(&value) {
+                self.current = Some((self.step)(value));
+                return Some(value);
+            }
+
+            // The value has not reached the interval yet, or is already past it.
+            let point = Bound::Value(value);
+            let before = if self.reverse {
+                point >= piece.upper
+            } else {
+                point <= piece.lower
+            };
+            if before {
+                self.current = Some((self.step)(value));
+            } else {
+                self.piece = self.pieces.next();
+            }
+        }
     }
 }
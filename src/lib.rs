@@ -14,18 +14,32 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
-pub use interval::Interval;
+pub use allen::AllenRelation;
+pub use dict::IntervalDict;
+pub use impls::{Discrete, Item, Numeric, OrderedFloat};
+pub use interval::{Bound, BoundKind, Interval, Replace, ReplaceMode};
+pub use notation::Notation;
 pub use ops::Operations;
+pub use parse::{ParseError, ParseErrorKind};
+pub use set::IntervalSet;
+pub use tree::IntervalTree;
 
-use crate::impls::Item;
 use crate::interval::IntervalType;
 use std::marker::PhantomData;
 
+pub mod allen;
+pub mod dict;
 pub(crate) mod helpers;
 pub(crate) mod impls;
 pub mod interval;
 pub(crate) mod iter;
+pub mod notation;
 pub mod ops;
+pub mod parse;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod set;
+pub mod tree;
 
 /// Blank type used for interval creation.
 pub struct Portion<T: Item> {
@@ -36,8 +50,8 @@ impl<T: Item> Portion<T> {
     /// Creates an open interval.
     pub fn open(lower: T, upper: T) -> Interval<T> {
         Interval {
-            lower: Some(lower),
-            upper: Some(upper),
+            lower: Bound::Value(lower),
+            upper: Bound::Value(upper),
             itype: IntervalType::Open,
         }
     }
@@ -45,8 +59,8 @@ impl<T: Item> Portion<T> {
     /// Creates a closed interval.
     pub fn closed(lower: T, upper: T) -> Interval<T> {
         Interval {
-            lower: Some(lower),
-            upper: Some(upper),
+            lower: Bound::Value(lower),
+            upper: Bound::Value(upper),
             itype: IntervalType::Closed,
         }
     }
@@ -54,8 +68,8 @@ impl<T: Item> Portion<T> {
     /// Creates an empty interval.
     pub fn empty() -> Interval<T> {
         Interval {
-            lower: None,
-            upper: None,
+            lower: Bound::PosInf,
+            upper: Bound::NegInf,
             itype: IntervalType::Empty,
         }
     }
@@ -63,8 +77,8 @@ impl<T: Item> Portion<T> {
     /// Creates a singleton interval.
     pub fn singleton(value: T) -> Interval<T> {
         Interval {
-            lower: Some(value),
-            upper: None,
+            lower: Bound::Value(value),
+            upper: Bound::Value(value),
             itype: IntervalType::Singleton,
         }
     }
@@ -72,8 +86,8 @@ impl<T: Item> Portion<T> {
     /// Creates an open-closed interval.
     pub fn openclosed(lower: T, upper: T) -> Interval<T> {
         Interval {
-            lower: Some(lower),
-            upper: Some(upper),
+            lower: Bound::Value(lower),
+            upper: Bound::Value(upper),
             itype: IntervalType::OpenClosed,
         }
     }
@@ -81,9 +95,54 @@ impl<T: Item> Portion<T> {
     /// Creates a closed-open interval.
     pub fn closedopen(lower: T, upper: T) -> Interval<T> {
         Interval {
-            lower: Some(lower),
-            upper: Some(upper),
+            lower: Bound::Value(lower),
+            upper: Bound::Value(upper),
             itype: IntervalType::ClosedOpen,
         }
     }
+
+    /// Creates an interval containing every value, `(-inf, +inf)`.
+    pub fn all() -> Interval<T> {
+        Interval {
+            lower: Bound::NegInf,
+            upper: Bound::PosInf,
+            itype: IntervalType::Open,
+        }
+    }
+
+    /// Creates an interval unbounded from the right, `(lower, +inf)`.
+    pub fn open_inf(lower: T) -> Interval<T> {
+        Interval {
+            lower: Bound::Value(lower),
+            upper: Bound::PosInf,
+            itype: IntervalType::Open,
+        }
+    }
+
+    /// Creates an interval unbounded from the right, `[lower, +inf)`.
+    pub fn closedopen_inf(lower: T) -> Interval<T> {
+        Interval {
+            lower: Bound::Value(lower),
+            upper: Bound::PosInf,
+            itype: IntervalType::ClosedOpen,
+        }
+    }
+
+    /// Creates an interval unbounded from the left, `(-inf, upper)`.
+    pub fn inf_open(upper: T) -> Interval<T> {
+        Interval {
+            lower: Bound::NegInf,
+            upper: Bound::Value(upper),
+            itype: IntervalType::Open,
+        }
+    }
+
+    /// Creates an interval unbounded from the left, `(-inf, upper]`.
+    pub fn inf_openclosed(upper: T) -> Interval<T> {
+        Interval {
+            lower: Bound::NegInf,
+            upper: Bound::Value(upper),
+            itype: IntervalType::OpenClosed,
+        }
+    }
 }
@@ -0,0 +1,158 @@
+//! Configurable string notation of intervals.
+
+use crate::impls::Item;
+use crate::interval::Bound;
+use crate::ops::Operations;
+use crate::parse::{ParseError, Parser};
+use crate::{Interval, IntervalSet};
+use std::fmt::{Display, Write};
+use std::str::FromStr;
+
+/// The tokens used to write and read intervals.
+///
+/// The default notation is the one used by `Display` and `FromStr`, such as `[1, 2) | (3, +inf)`.
+/// Tokens are matched ignoring surrounding whitespace when parsing.
+/// A token of only whitespace, such as a `" "` separator, matches any whitespace between two other tokens.
+///
+/// ```
+/// use portion_rs::{Interval, Notation, Portion};
+///
+/// let notation = Notation {
+///     left_open: "]",
+///     right_open: "[",
+///     ..Notation::default()
+/// };
+/// assert_eq!("]1, 2]", notation.format(&Portion::openclosed(1, 2)));
+///
+/// let x: Interval<i32> = notation.parse("[1, 2[").unwrap();
+/// assert_eq!(x, Portion::closedopen(1, 2));
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Notation<'a> {
+    /// Starts an interval open from the left.
+    pub left_open: &'a str,
+    /// Starts an interval closed from the left.
+    pub left_closed: &'a str,
+    /// Ends an interval open from the right.
+    pub right_open: &'a str,
+    /// Ends an interval closed from the right.
+    pub right_closed: &'a str,
+    /// Separates the lower and upper points.
+    pub separator: &'a str,
+    /// Separates the intervals of a union.
+    pub union: &'a str,
+    /// Stands for an empty interval.
+    pub empty: &'a str,
+    /// Stands for negative infinity.
+    pub neg_inf: &'a str,
+    /// Stands for positive infinity.
+    pub pos_inf: &'a str,
+}
+
+impl Notation<'static> {
+    pub(crate) const DEFAULT: Notation<'static> = Notation {
+        left_open: "(",
+        left_closed: "[",
+        right_open: ")",
+        right_closed: "]",
+        separator: ", ",
+        union: " | ",
+        empty: "()",
+        neg_inf: "-inf",
+        pos_inf: "+inf",
+    };
+}
+
+impl Default for Notation<'static> {
+    fn default() -> Self {
+        Notation::DEFAULT
+    }
+}
+
+impl<'a> Notation<'a> {
+    /// Writes an interval in this notation.
+    pub fn format<T: Item + Display>(&self, interval: &Interval<T>) -> String {
+        let mut s = String::new();
+        self.write_interval(&mut s, interval).unwrap();
+        s
+    }
+
+    /// Writes a set of intervals in this notation.
+    pub fn format_set<T: Item + Display>(&self, set: &IntervalSet<T>) -> String {
+        let mut s = String::new();
+        self.write_set(&mut s, set).unwrap();
+        s
+    }
+
+    /// Reads an interval written in this notation.
+    pub fn parse<T: Item + FromStr>(&self, s: &str) -> Result<Interval<T>, ParseError> {
+        let mut parser = Parser::new(self, s);
+        let interval = parser.interval()?;
+        parser.end()?;
+        Ok(interval)
+    }
+
+    /// Reads a set of intervals written in this notation.
+    pub fn parse_set<T: Item + FromStr>(&self, s: &str) -> Result<IntervalSet<T>, ParseError> {
+        let mut parser = Parser::new(self, s);
+        let mut intervals = vec![parser.interval()?];
+        while parser.eat(self.union) {
+            intervals.push(parser.interval()?);
+        }
+        parser.end()?;
+        Ok(IntervalSet::normalize(intervals))
+    }
+
+    fn write_point<T: Display, W: Write>(&self, w: &mut W, point: &Bound<T>) -> std::fmt::Result {
+        match point {
+            Bound::NegInf => w.write_str(self.neg_inf),
+            Bound::Value(value) => write!(w, "{}", value),
+            Bound::PosInf => w.write_str(self.pos_inf),
+        }
+    }
+
+    pub(crate) fn write_interval<T: Item + Display, W: Write>(
+        &self,
+        w: &mut W,
+        interval: &Interval<T>,
+    ) -> std::fmt::Result {
+        if interval.empty() {
+            return w.write_str(self.empty);
+        }
+
+        if interval.singleton() {
+            w.write_str(self.left_closed)?;
+            self.write_point(w, &interval.lower)?;
+            return w.write_str(self.right_closed);
+        }
+
+        w.write_str(if interval.left_closed() {
+            self.left_closed
+        } else {
+            self.left_open
+        })?;
+        self.write_point(w, &interval.lower)?;
+        w.write_str(self.separator)?;
+        self.write_point(w, &interval.upper)?;
+        w.write_str(if interval.right_closed() {
+            self.right_closed
+        } else {
+            self.right_open
+        })
+    }
+
+    pub(crate) fn write_set<T: Item + Display, W: Write>(&self, w: &mut W, set: &IntervalSet<T>) -> std::fmt::Result {
+        if set.empty() {
+            return w.write_str(self.empty);
+        }
+
+        for (i, interval) in set.intervals.iter().enumerate() {
+            if i > 0 {
+                w.write_str(self.union)?;
+            }
+            self.write_interval(w, interval)?;
+        }
+
+        Ok(())
+    }
+}
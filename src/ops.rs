@@ -1,15 +1,19 @@
 //! Operations defined on intervals.
 
-use crate::helpers::{LeftBound, RightBound};
-use crate::impls::Item;
-use crate::{Interval, IntervalType, Portion};
-use std::ops::{BitAnd, BitOr};
+use crate::helpers::{find_containing, sub_side};
+use crate::impls::{Item, Numeric};
+use crate::interval::Bound;
+use crate::{Interval, IntervalSet, IntervalType, Portion};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Mul, Not, Sub};
 
 /// Operations defined on interval-like things.
 pub trait Operations: Sized {
     /// The return type used in implementations.
     type Output;
 
+    /// The type of values held by the interval.
+    type Value;
+
     /// Returns whether the interval is empty, regardless of it's actual type.
     fn empty(&self) -> bool {
         true
@@ -20,19 +24,28 @@ pub trait Operations: Sized {
         true
     }
 
+    /// Returns whether the interval contains the value.
+    fn contains(&self, value: &Self::Value) -> bool;
+
     /// Returns the intersection of two intervals, shorthand for `interval & interval`.
-    fn intersection(self, _other: Self::Output) -> Self::Output {
-        unimplemented!()
-    }
+    fn intersection(self, other: Self) -> Self::Output;
 
     /// Returns the union of two intervals, shorthand for `interval | interval`.
-    fn union(self, _other: Self::Output) -> Self::Output {
-        unimplemented!()
-    }
+    fn union(self, other: Self) -> Self::Output;
+
+    /// Returns the complement of an interval, shorthand for `!interval`.
+    fn complement(self) -> Self::Output;
+
+    /// Returns the difference of two intervals, shorthand for `interval - interval`.
+    fn difference(self, other: Self) -> Self::Output;
+
+    /// Returns the symmetric difference of two intervals, shorthand for `interval ^ interval`.
+    fn symmetric_difference(self, other: Self) -> Self::Output;
 }
 
 impl<T: Item> Operations for Interval<T> {
-    type Output = Self;
+    type Output = IntervalSet<T>;
+    type Value = T;
 
     fn empty(&self) -> bool {
         use IntervalType::*;
@@ -46,13 +59,77 @@ impl<T: Item> Operations for Interval<T> {
         }
     }
 
-    fn intersection(self, other: Self::Output) -> Self::Output {
+    fn contains(&self, value: &T) -> bool {
+        let point = Bound::Value(*value);
+        let left = if self.left_closed() {
+            self.lower <= point
+        } else {
+            self.lower < point
+        };
+        let right = if self.right_closed() {
+            point <= self.upper
+        } else {
+            point < self.upper
+        };
+        left && right
+    }
+
+    fn intersection(self, other: Self) -> Self::Output {
+        (self & other).into()
+    }
+
+    fn union(self, other: Self) -> Self::Output {
+        self | other
+    }
+
+    fn complement(self) -> Self::Output {
+        !self
+    }
+
+    fn difference(self, other: Self) -> Self::Output {
+        self - other
+    }
+
+    fn symmetric_difference(self, other: Self) -> Self::Output {
+        self ^ other
+    }
+}
+
+impl<T: Item> Operations for IntervalSet<T> {
+    type Output = Self;
+    type Value = T;
+
+    fn empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    fn atomic(&self) -> bool {
+        self.intervals.len() <= 1
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        find_containing(&self.intervals, value, |x| x).is_some()
+    }
+
+    fn intersection(self, other: Self) -> Self::Output {
         self & other
     }
 
-    fn union(self, other: Self::Output) -> Self::Output {
+    fn union(self, other: Self) -> Self::Output {
         self | other
     }
+
+    fn complement(self) -> Self::Output {
+        !self
+    }
+
+    fn difference(self, other: Self) -> Self::Output {
+        self - other
+    }
+
+    fn symmetric_difference(self, other: Self) -> Self::Output {
+        self ^ other
+    }
 }
 
 // Intersection.
@@ -65,82 +142,192 @@ impl<T: Item> BitAnd for Interval<T> {
             return Portion::empty();
         }
 
-        // Handle singletons first.
-        if self.singleton() {
-            if rhs.contains(self.lower()) {
-                return self;
-            } else {
-                return Portion::empty();
-            }
-        }
-
-        if rhs.singleton() {
-            if self.contains(rhs.lower.unwrap()) {
-                return rhs;
-            } else {
-                return Portion::empty();
-            }
-        }
-
         // Optimization.
-        if self.upper < rhs.lower {
+        if !self.overlaps(&rhs) {
             return Portion::empty();
         }
 
         // Match the bounds.
-        let left_bound = self.get_left_bound(&rhs);
-        let right_bound = self.get_right_bound(&rhs);
-
-        match left_bound {
-            LeftBound::Open(lower) => match right_bound {
-                RightBound::Open(upper) => Portion::open(lower, upper),
-                RightBound::Closed(upper) => Portion::openclosed(lower, upper),
-                RightBound::None => Portion::empty(),
-            },
-            LeftBound::Closed(lower) => match right_bound {
-                RightBound::Open(upper) => Portion::closedopen(lower, upper),
-                RightBound::Closed(upper) => Portion::closed(lower, upper),
-                RightBound::None => Portion::empty(),
-            },
-            LeftBound::None => Portion::empty(),
+        Interval::from_bounds(self.get_left_bound(&rhs), self.get_right_bound(&rhs))
+    }
+}
+
+impl<T: Item> BitAnd for IntervalSet<T> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        let mut intervals = Vec::new();
+        for x in &self.intervals {
+            for y in &rhs.intervals {
+                intervals.push(*x & *y);
+            }
         }
+        IntervalSet::normalize(intervals)
+    }
+}
+
+impl<T: Item> BitAnd<Interval<T>> for IntervalSet<T> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Interval<T>) -> Self::Output {
+        self & IntervalSet::from(rhs)
+    }
+}
+
+impl<T: Item> BitAnd<IntervalSet<T>> for Interval<T> {
+    type Output = IntervalSet<T>;
+
+    fn bitand(self, rhs: IntervalSet<T>) -> Self::Output {
+        IntervalSet::from(self) & rhs
     }
 }
 
 // Union.
 impl<T: Item> BitOr for Interval<T> {
+    type Output = IntervalSet<T>;
+
+    fn bitor(self, rhs: Interval<T>) -> Self::Output {
+        IntervalSet::normalize(vec![self, rhs])
+    }
+}
+
+impl<T: Item> BitOr for IntervalSet<T> {
+    type Output = Self;
+
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self.intervals.extend(rhs.intervals);
+        IntervalSet::normalize(self.intervals)
+    }
+}
+
+impl<T: Item> BitOr<Interval<T>> for IntervalSet<T> {
     type Output = Self;
 
     fn bitor(self, rhs: Interval<T>) -> Self::Output {
-        if self.empty() {
-            return rhs;
-        }
+        self | IntervalSet::from(rhs)
+    }
+}
+
+impl<T: Item> BitOr<IntervalSet<T>> for Interval<T> {
+    type Output = IntervalSet<T>;
+
+    fn bitor(self, rhs: IntervalSet<T>) -> Self::Output {
+        IntervalSet::from(self) | rhs
+    }
+}
+
+// Complement.
+impl<T: Item> Not for Interval<T> {
+    type Output = IntervalSet<T>;
+
+    fn not(self) -> Self::Output {
+        !IntervalSet::from(self)
+    }
+}
+
+impl<T: Item> Not for IntervalSet<T> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.gaps()
+    }
+}
+
+// Difference.
+impl<T: Item> Sub for Interval<T> {
+    type Output = IntervalSet<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self & !rhs
+    }
+}
+
+impl<T: Item> Sub for IntervalSet<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self & !rhs
+    }
+}
+
+impl<T: Item> Sub<Interval<T>> for IntervalSet<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Interval<T>) -> Self::Output {
+        self & !rhs
+    }
+}
+
+impl<T: Item> Sub<IntervalSet<T>> for Interval<T> {
+    type Output = IntervalSet<T>;
 
-        if rhs.empty() {
+    fn sub(self, rhs: IntervalSet<T>) -> Self::Output {
+        self & !rhs
+    }
+}
+
+// Symmetric difference.
+impl<T: Item> BitXor for Interval<T> {
+    type Output = IntervalSet<T>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        (self - rhs) | (rhs - self)
+    }
+}
+
+impl<T: Item> BitXor for IntervalSet<T> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        (self.clone() - rhs.clone()) | (rhs - self)
+    }
+}
+
+impl<T: Item> BitXor<Interval<T>> for IntervalSet<T> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Interval<T>) -> Self::Output {
+        self ^ IntervalSet::from(rhs)
+    }
+}
+
+impl<T: Item> BitXor<IntervalSet<T>> for Interval<T> {
+    type Output = IntervalSet<T>;
+
+    fn bitxor(self, rhs: IntervalSet<T>) -> Self::Output {
+        IntervalSet::from(self) ^ rhs
+    }
+}
+
+// Translation.
+impl<T: Numeric> Add<T> for Interval<T> {
+    type Output = Interval<T>;
+
+    fn add(self, rhs: T) -> Interval<T> {
+        self.sum(&Portion::singleton(rhs))
+    }
+}
+
+impl<T: Numeric> Sub<T> for Interval<T> {
+    type Output = Interval<T>;
+
+    fn sub(self, rhs: T) -> Interval<T> {
+        if self.empty() {
             return self;
         }
 
-        // TODO: return a union of two intervals?
-        #[allow(clippy::suspicious_operation_groupings)]
-        if !self.singleton() && !rhs.singleton() && self.upper < rhs.lower {
-            return Portion::empty();
-        }
+        Interval::from_points(
+            sub_side((self.lower, self.left_closed()), rhs),
+            sub_side((self.upper, self.right_closed()), rhs),
+        )
+    }
+}
 
-        let left_val = self.get_lowest_val(&rhs);
-        let right_val = self.get_highest_val(&rhs);
-
-        match left_val {
-            LeftBound::Closed(lower) => match right_val {
-                RightBound::Closed(upper) => Portion::closed(lower, upper),
-                RightBound::Open(upper) => Portion::closedopen(lower, upper),
-                RightBound::None => unreachable!(),
-            },
-            LeftBound::Open(lower) => match right_val {
-                RightBound::Closed(upper) => Portion::openclosed(lower, upper),
-                RightBound::Open(upper) => Portion::open(lower, upper),
-                RightBound::None => unreachable!(),
-            },
-            LeftBound::None => unreachable!(),
-        }
+// Scaling.
+impl<T: Numeric> Mul<T> for Interval<T> {
+    type Output = Interval<T>;
+
+    fn mul(self, rhs: T) -> Interval<T> {
+        self.product(&Portion::singleton(rhs))
     }
 }
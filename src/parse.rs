@@ -0,0 +1,241 @@
+//! Parsing of intervals from their string notation.
+
+use crate::helpers::{LeftBound, RightBound};
+use crate::impls::Item;
+use crate::interval::Bound;
+use crate::{Interval, IntervalSet, Notation, Portion};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The error returned when an interval cannot be parsed.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct ParseError {
+    position: usize,
+    kind: ParseErrorKind,
+}
+
+/// The reason an interval could not be parsed.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum ParseErrorKind {
+    /// The input ended before the interval was complete.
+    UnexpectedEnd,
+    /// A character that does not fit the notation was found.
+    UnexpectedChar(char),
+    /// A value could not be parsed into the bound type.
+    InvalidValue,
+    /// An infinite point was used with a closed bound.
+    ClosedInfinity,
+    /// A union of several intervals was found where a single one was expected.
+    NotAtomic,
+}
+
+impl ParseError {
+    /// Returns the byte offset in the input where the error occurred.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the reason of the error.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use ParseErrorKind::*;
+        match self.kind {
+            UnexpectedEnd => write!(f, "unexpected end of input")?,
+            UnexpectedChar(c) => write!(f, "unexpected character `{}`", c)?,
+            InvalidValue => write!(f, "invalid value")?,
+            ClosedInfinity => write!(f, "infinite bound must be open")?,
+            NotAtomic => write!(f, "expected a single interval")?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl<T: Item + FromStr> FromStr for Interval<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Notation::DEFAULT.parse(s)
+    }
+}
+
+impl<T: Item + FromStr> FromStr for IntervalSet<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Notation::DEFAULT.parse_set(s)
+    }
+}
+
+// Reads intervals written in a notation.
+pub(crate) struct Parser<'a, 'b> {
+    notation: &'a Notation<'a>,
+    input: &'b str,
+    position: usize,
+}
+
+impl<'a, 'b> Parser<'a, 'b> {
+    pub(crate) fn new(notation: &'a Notation<'a>, input: &'b str) -> Self {
+        Parser {
+            notation,
+            input,
+            position: 0,
+        }
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            position: self.position,
+            kind,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn unexpected(&self) -> ParseError {
+        match self.input[self.position..].chars().next() {
+            Some(c) => self.error(ParseErrorKind::UnexpectedChar(c)),
+            None => self.error(ParseErrorKind::UnexpectedEnd),
+        }
+    }
+
+    // Consumes the token if it comes next, ignoring whitespace.
+    // A token of only whitespace matches the whitespace between two other tokens, an empty one never matches.
+    pub(crate) fn eat(&mut self, token: &str) -> bool {
+        let position = self.position;
+        self.skip_whitespace();
+        let matched = match token.trim() {
+            "" => !token.is_empty() && self.position > position && self.position < self.input.len(),
+            trimmed if self.input[self.position..].starts_with(trimmed) => {
+                self.position += trimmed.len();
+                true
+            }
+            _ => false,
+        };
+        if !matched {
+            self.position = position;
+        }
+        matched
+    }
+
+    // Consumes the next token, which must be one of the expected ones, and returns its index.
+    fn expect(&mut self, expected: &[&str]) -> Result<usize, ParseError> {
+        match expected.iter().position(|token| self.eat(token)) {
+            Some(index) => Ok(index),
+            None => {
+                self.skip_whitespace();
+                Err(self.unexpected())
+            }
+        }
+    }
+
+    // Returns whether nothing but whitespace is left.
+    fn blank(&self) -> bool {
+        self.input[self.position..].trim().is_empty()
+    }
+
+    // Ensures nothing but whitespace is left.
+    pub(crate) fn end(&mut self) -> Result<(), ParseError> {
+        let position = self.position;
+        if self.eat(self.notation.union) {
+            self.position = position;
+            self.skip_whitespace();
+            return Err(self.error(ParseErrorKind::NotAtomic));
+        }
+
+        self.skip_whitespace();
+        if self.position < self.input.len() {
+            return Err(self.unexpected());
+        }
+        Ok(())
+    }
+
+    // Reads a point up to the first terminator, without consuming the terminator.
+    fn point<T: FromStr>(&mut self, terminators: &[&str]) -> Result<Bound<T>, ParseError> {
+        self.skip_whitespace();
+        let start = self.position;
+        let rest = &self.input[start..];
+        let length = terminators
+            .iter()
+            .filter_map(|token| match token.trim() {
+                "" if token.is_empty() => None,
+                "" => rest.find(char::is_whitespace),
+                token => rest.find(token),
+            })
+            .min();
+        let length = match length {
+            Some(length) => length,
+            None => {
+                self.position = self.input.len();
+                return Err(self.error(ParseErrorKind::UnexpectedEnd));
+            }
+        };
+        self.position += length;
+
+        let value = rest[..length].trim_end();
+        if value == self.notation.neg_inf.trim() {
+            return Ok(Bound::NegInf);
+        }
+        if value == self.notation.pos_inf.trim() {
+            return Ok(Bound::PosInf);
+        }
+        value.parse().map(Bound::Value).map_err(|_| ParseError {
+            position: start,
+            kind: ParseErrorKind::InvalidValue,
+        })
+    }
+
+    // Reads a single atomic interval.
+    pub(crate) fn interval<T: Item + FromStr>(&mut self) -> Result<Interval<T>, ParseError> {
+        let n = self.notation;
+        // An empty token of only whitespace can only stand for the whole input.
+        let empty = match n.empty.trim() {
+            "" => self.blank(),
+            _ => self.eat(n.empty),
+        };
+        if empty {
+            return Ok(Portion::empty());
+        }
+
+        let closed = self.expect(&[n.left_closed, n.left_open])? == 0;
+        self.skip_whitespace();
+        let lower_position = self.position;
+        let lower = self.point(&[n.separator, n.right_closed, n.right_open])?;
+        let left = match closed {
+            true if lower.infinite() => return Err(self.closed_infinity(lower_position)),
+            true => LeftBound::Closed(lower),
+            false => LeftBound::Open(lower),
+        };
+
+        // A singleton has a single point between closed bounds.
+        if closed && self.eat(n.right_closed) {
+            return Ok(Interval::from_bounds(left, RightBound::Closed(lower)));
+        }
+        self.expect(&[n.separator])?;
+
+        self.skip_whitespace();
+        let upper_position = self.position;
+        let upper = self.point(&[n.right_closed, n.right_open])?;
+        let right = match self.expect(&[n.right_closed, n.right_open])? {
+            0 if upper.infinite() => return Err(self.closed_infinity(upper_position)),
+            0 => RightBound::Closed(upper),
+            _ => RightBound::Open(upper),
+        };
+
+        Ok(Interval::from_bounds(left, right))
+    }
+
+    fn closed_infinity(&mut self, position: usize) -> ParseError {
+        self.position = position;
+        self.error(ParseErrorKind::ClosedInfinity)
+    }
+}
@@ -0,0 +1,110 @@
+//! Serde support, enabled by the `serde` feature.
+//!
+//! Intervals are serialized as structures such as `{"left": "closed", "lower": 1, "upper": 2, "right": "open"}`,
+//! where a missing point is infinite and an empty interval is `null`. Sets are sequences of such structures.
+//!
+//! The [`compact`] module serializes them as strings in the `Display` notation instead.
+
+use crate::helpers::{LeftBound, RightBound};
+use crate::impls::Item;
+use crate::interval::Bound;
+use crate::ops::Operations;
+use crate::{BoundKind, Interval, IntervalSet, Portion};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+struct Atomic<T> {
+    left: BoundKind,
+    lower: Option<T>,
+    upper: Option<T>,
+    right: BoundKind,
+}
+
+impl<T: Item + Serialize> Serialize for Interval<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.empty() {
+            return serializer.serialize_none();
+        }
+
+        serializer.serialize_some(&Atomic {
+            left: self.left(),
+            lower: self.lower.value(),
+            upper: self.upper.value(),
+            right: self.right(),
+        })
+    }
+}
+
+impl<'de, T: Item + Deserialize<'de>> Deserialize<'de> for Interval<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let atomic = match Option::<Atomic<T>>::deserialize(deserializer)? {
+            Some(atomic) => atomic,
+            None => return Ok(Portion::empty()),
+        };
+
+        let left = match (atomic.left, atomic.lower) {
+            (BoundKind::Closed, None) => return Err(D::Error::custom("infinite bound must be open")),
+            (BoundKind::Closed, Some(lower)) => LeftBound::Closed(Bound::Value(lower)),
+            (BoundKind::Open, lower) => LeftBound::Open(lower.map_or(Bound::NegInf, Bound::Value)),
+        };
+
+        let right = match (atomic.right, atomic.upper) {
+            (BoundKind::Closed, None) => return Err(D::Error::custom("infinite bound must be open")),
+            (BoundKind::Closed, Some(upper)) => RightBound::Closed(Bound::Value(upper)),
+            (BoundKind::Open, upper) => RightBound::Open(upper.map_or(Bound::PosInf, Bound::Value)),
+        };
+
+        Ok(Interval::from_bounds(left, right))
+    }
+}
+
+impl<T: Item + Serialize> Serialize for IntervalSet<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.intervals.serialize(serializer)
+    }
+}
+
+impl<'de, T: Item + Deserialize<'de>> Deserialize<'de> for IntervalSet<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(IntervalSet::normalize)
+    }
+}
+
+/// Serializes intervals and sets as strings in the `Display` notation.
+///
+/// ```
+/// use portion_rs::{Interval, Portion};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Booking {
+///     #[serde(with = "portion_rs::serialization::compact")]
+///     hours: Interval<u8>,
+/// }
+///
+/// let booking = Booking { hours: Portion::closedopen(9, 17) };
+/// assert_eq!(r#"{"hours":"[9, 17)"}"#, serde_json::to_string(&booking).unwrap());
+/// ```
+pub mod compact {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    /// Serializes the value as its `Display` string.
+    pub fn serialize<V: Display, S: Serializer>(value: &V, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    /// Deserializes the value from its `Display` string.
+    pub fn deserialize<'de, V, D>(deserializer: D) -> Result<V, D::Error>
+    where
+        V: FromStr,
+        V::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(D::Error::custom)
+    }
+}
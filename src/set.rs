@@ -0,0 +1,250 @@
+//! Holds the interval set type and associated methods.
+
+use crate::helpers::{add_points, LeftBound, RightBound};
+use crate::impls::{Discrete, Item, Numeric};
+use crate::interval::Bound;
+use crate::iter::{Iterate, Values};
+use crate::ops::Operations;
+use crate::{Interval, Notation};
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::iter::{Copied, FromIterator};
+use std::ops::Index;
+use std::{slice, vec};
+
+/// A union of disjoint atomic intervals, kept sorted and merged.
+#[derive(Clone, Debug)]
+pub struct IntervalSet<T: Item> {
+    pub(crate) intervals: Vec<Interval<T>>,
+}
+
+impl<T: Item> IntervalSet<T> {
+    /// Returns whether every value of the interval is also in this set.
+    pub fn contains_interval(&self, interval: &Interval<T>) -> bool {
+        interval.empty() || self.intervals.iter().any(|x| x.contains_interval(interval))
+    }
+
+    /// Returns whether the sets share at least one value.
+    pub fn overlaps(&self, other: &IntervalSet<T>) -> bool {
+        self.intervals
+            .iter()
+            .any(|x| other.intervals.iter().any(|y| x.overlaps(y)))
+    }
+
+    /// Returns whether every value of the set is also in the other one.
+    pub fn is_subset(&self, other: &IntervalSet<T>) -> bool {
+        self.intervals.iter().all(|x| other.contains_interval(x))
+    }
+
+    /// Returns whether every value of the other set is also in this one.
+    pub fn is_superset(&self, other: &IntervalSet<T>) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns the number of atomic intervals in the set.
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Returns whether the set has no intervals.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns the atomic interval at the index, in order.
+    pub fn get(&self, index: usize) -> Option<&Interval<T>> {
+        self.intervals.get(index)
+    }
+
+    /// Iterates over the atomic intervals, in order.
+    ///
+    /// ```
+    /// use portion_rs::Portion;
+    ///
+    /// let x = Portion::closed(1, 2) | Portion::open(4, 5);
+    /// let pieces: Vec<String> = x.iter().map(|x| x.to_string()).collect();
+    /// assert_eq!(pieces, vec!["[1, 2]", "(4, 5)"]);
+    /// assert_eq!("(4, 5)", x[1].to_string());
+    /// ```
+    pub fn iter(&self) -> Copied<slice::Iter<'_, Interval<T>>> {
+        self.intervals.iter().copied()
+    }
+
+    /// Iterates over the values of every interval, starting from the lowest point and applying `step`.
+    ///
+    /// Values between the intervals are skipped. Yields nothing if the set is unbounded from the left.
+    ///
+    /// ```
+    /// use portion_rs::{OrderedFloat, Portion};
+    ///
+    /// let f = OrderedFloat;
+    /// let x = Portion::closed(f(0.0), f(1.0)) | Portion::closed(f(2.0), f(3.0));
+    /// let values: Vec<f64> = x.iterate(|x| f(x.0 + 0.75)).map(|x| x.0).collect();
+    /// assert_eq!(values, vec![0.0, 0.75, 2.25, 3.0]);
+    /// ```
+    pub fn iterate<F: Fn(T) -> T>(&self, step: F) -> Iterate<T, F> {
+        Iterate::new(self.intervals.clone(), step, false)
+    }
+
+    /// Iterates over the values of every interval, starting from the highest point and applying `step`.
+    ///
+    /// Values between the intervals are skipped. Yields nothing if the set is unbounded from the right.
+    pub fn iterate_rev<F: Fn(T) -> T>(&self, step: F) -> Iterate<T, F> {
+        Iterate::new(self.intervals.clone(), step, true)
+    }
+
+    /// Maps every atomic interval with the function and merges the results into a new set, like `apply` in Python.
+    ///
+    /// ```
+    /// use portion_rs::{BoundKind, Portion, Replace};
+    ///
+    /// let x = Portion::closed(1, 2) | Portion::closed(4, 5);
+    /// let y = x.apply(|x| {
+    ///     x.replace(Replace {
+    ///         right: Some(BoundKind::Open),
+    ///         ..Replace::default()
+    ///     })
+    /// });
+    /// assert_eq!("[1, 2) | [4, 5)", y.to_string());
+    /// ```
+    pub fn apply<R: Into<IntervalSet<T>>, F: Fn(Interval<T>) -> R>(&self, f: F) -> IntervalSet<T> {
+        let intervals = self.iter().flat_map(|x| f(x).into().intervals).collect();
+        IntervalSet::normalize(intervals)
+    }
+
+    // Sorts the intervals and merges the ones that overlap or touch.
+    pub(crate) fn normalize(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.retain(|x| !x.empty());
+        intervals.sort_by(|a, b| a.cmp_lower(b));
+
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if last.overlaps(&interval) || last.adjacent(&interval) => *last = last.hull(&interval),
+                _ => merged.push(interval),
+            }
+        }
+
+        IntervalSet { intervals: merged }
+    }
+
+    // Collects the gaps around the intervals, which are already sorted and disjoint.
+    pub(crate) fn gaps(&self) -> Self {
+        let mut intervals = Vec::with_capacity(self.intervals.len() + 1);
+        let mut left = LeftBound::Open(Bound::NegInf);
+        for interval in &self.intervals {
+            intervals.push(Interval::from_bounds(left, interval.get_before_bound()));
+            left = interval.get_after_bound();
+        }
+        intervals.push(Interval::from_bounds(left, RightBound::Open(Bound::PosInf)));
+        IntervalSet::normalize(intervals)
+    }
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    /// Returns the set in its canonical closed form, treating values as discrete.
+    ///
+    /// Intervals without any value between them are merged, so `[1, 2] | [3, 4]` becomes `[1, 4]`.
+    pub fn discrete(self) -> Self {
+        let intervals = self.intervals.into_iter().map(Interval::discrete).collect();
+        let normalized = IntervalSet::normalize(intervals);
+
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(normalized.intervals.len());
+        for interval in normalized.intervals {
+            match merged.last_mut() {
+                Some(last) if last.adjacent_discrete(&interval) => *last = last.hull(&interval),
+                _ => merged.push(interval),
+            }
+        }
+
+        IntervalSet { intervals: merged }
+    }
+
+    /// Iterates over the values of every interval, in order.
+    ///
+    /// ```
+    /// use portion_rs::Portion;
+    ///
+    /// let x = Portion::closed(1, 2) | Portion::open(4, 7);
+    /// assert_eq!(vec![1, 2, 5, 6], x.values().collect::<Vec<_>>());
+    /// ```
+    pub fn values(&self) -> Values<'_, T> {
+        Values {
+            inner: self.iter().flatten(),
+        }
+    }
+
+    /// Returns the number of values in the set, or `None` if it does not fit `usize`.
+    pub fn count(&self) -> Option<usize> {
+        self.intervals
+            .iter()
+            .try_fold(0usize, |total, x| total.checked_add(x.count()?))
+    }
+}
+
+impl<T: Numeric> IntervalSet<T> {
+    /// Returns the total length of the intervals, `+inf` if unbounded or if it does not fit the type.
+    pub fn measure(&self) -> Bound<T> {
+        self.intervals
+            .iter()
+            .fold(Bound::Value(T::zero()), |total, x| add_points(total, x.length()))
+    }
+}
+
+impl<T: Item> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        IntervalSet::normalize(vec![interval])
+    }
+}
+
+impl<T: Item> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        IntervalSet::normalize(iter.into_iter().collect())
+    }
+}
+
+impl<T: Item> Index<usize> for IntervalSet<T> {
+    type Output = Interval<T>;
+
+    fn index(&self, index: usize) -> &Interval<T> {
+        &self.intervals[index]
+    }
+}
+
+impl<T: Item> IntoIterator for IntervalSet<T> {
+    type Item = Interval<T>;
+    type IntoIter = vec::IntoIter<Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.into_iter()
+    }
+}
+
+impl<'a, T: Item> IntoIterator for &'a IntervalSet<T> {
+    type Item = Interval<T>;
+    type IntoIter = Copied<slice::Iter<'a, Interval<T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Item> PartialEq for IntervalSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.intervals == other.intervals
+    }
+}
+
+impl<T: Item> Eq for IntervalSet<T> {}
+
+impl<T: Item + Hash> Hash for IntervalSet<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.intervals.hash(state);
+    }
+}
+
+impl<T: Item + Display> Display for IntervalSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Notation::DEFAULT.write_set(f, self)
+    }
+}
@@ -0,0 +1,349 @@
+//! Holds the interval tree type and associated methods.
+
+use crate::impls::Item;
+use crate::interval::Bound;
+use crate::ops::Operations;
+use crate::Interval;
+use std::cmp::Ordering;
+use std::iter::FromIterator;
+
+/// A collection of possibly overlapping intervals with values, indexed for fast queries.
+///
+/// It is a balanced search tree ordered by interval, where every node also knows the highest
+/// upper point of its subtree, so queries skip the subtrees that cannot match.
+///
+/// ```
+/// use portion_rs::{IntervalTree, Portion};
+///
+/// let mut tree = IntervalTree::new();
+/// tree.insert(Portion::closedopen(9, 12), "standup");
+/// tree.insert(Portion::closedopen(11, 13), "lunch");
+/// tree.insert(Portion::closedopen(14, 15), "review");
+///
+/// let now: Vec<&str> = tree.containing(&11).into_iter().map(|(_, x)| *x).collect();
+/// assert_eq!(now, vec!["standup", "lunch"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct IntervalTree<T: Item, V> {
+    root: Link<T, V>,
+    len: usize,
+    // Tells apart entries with equal intervals, keeping them in insertion order.
+    next_id: u64,
+}
+
+type Link<T, V> = Option<Box<Node<T, V>>>;
+
+#[derive(Clone, Debug)]
+struct Node<T: Item, V> {
+    interval: Interval<T>,
+    id: u64,
+    value: V,
+    height: usize,
+    // The highest upper point in the subtree, closed after open.
+    max_end: (Bound<T>, bool),
+    left: Link<T, V>,
+    right: Link<T, V>,
+}
+
+impl<T: Item, V> IntervalTree<T, V> {
+    /// Creates an empty tree.
+    pub fn new() -> Self {
+        IntervalTree {
+            root: None,
+            len: 0,
+            next_id: 0,
+        }
+    }
+
+    /// Returns the number of stored intervals.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the tree stores no intervals.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Stores the interval with its value, keeping intervals already equal to it.
+    pub fn insert(&mut self, interval: Interval<T>, value: V) {
+        let node = Box::new(Node {
+            interval,
+            id: self.next_id,
+            value,
+            height: 1,
+            max_end: end(&interval),
+            left: None,
+            right: None,
+        });
+        self.root = Some(insert(self.root.take(), node));
+        self.next_id += 1;
+        self.len += 1;
+    }
+
+    /// Removes the earliest stored interval equal to the given one, returning its value.
+    pub fn remove(&mut self, interval: &Interval<T>) -> Option<V> {
+        let id = find(&self.root, interval)?;
+        let (root, value) = remove(self.root.take(), &(*interval, id));
+        self.root = root;
+        self.len -= 1;
+        value
+    }
+
+    /// Returns the stored intervals containing the point, in order.
+    pub fn containing(&self, value: &T) -> Vec<(Interval<T>, &V)> {
+        let mut found = Vec::new();
+        containing(&self.root, value, &mut found);
+        found
+    }
+
+    /// Returns the stored intervals sharing at least one value with the interval, in order.
+    pub fn overlapping(&self, interval: &Interval<T>) -> Vec<(Interval<T>, &V)> {
+        let mut found = Vec::new();
+        if !interval.empty() {
+            overlapping(&self.root, interval, &mut found);
+        }
+        found
+    }
+
+    /// Iterates over the stored intervals and their values, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (Interval<T>, &V)> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(&self.root);
+        iter
+    }
+}
+
+impl<T: Item, V> Default for IntervalTree<T, V> {
+    fn default() -> Self {
+        IntervalTree::new()
+    }
+}
+
+impl<T: Item, V> FromIterator<(Interval<T>, V)> for IntervalTree<T, V> {
+    fn from_iter<I: IntoIterator<Item = (Interval<T>, V)>>(iter: I) -> Self {
+        let mut tree = IntervalTree::new();
+        for (interval, value) in iter {
+            tree.insert(interval, value);
+        }
+        tree
+    }
+}
+
+// In-order traversal over borrowed nodes.
+struct Iter<'a, T: Item, V> {
+    stack: Vec<&'a Node<T, V>>,
+}
+
+impl<'a, T: Item, V> Iter<'a, T, V> {
+    fn push_left(&mut self, mut link: &'a Link<T, V>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'a, T: Item, V> Iterator for Iter<'a, T, V> {
+    type Item = (Interval<T>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        Some((node.interval, &node.value))
+    }
+}
+
+impl<T: Item, V> Node<T, V> {
+    fn key(&self) -> (Interval<T>, u64) {
+        (self.interval, self.id)
+    }
+
+    // Recomputes the height and highest upper point from the children.
+    fn update(&mut self) {
+        self.height = 1 + height(&self.left).max(height(&self.right));
+        self.max_end = end(&self.interval);
+        if let Some(left) = &self.left {
+            self.max_end = self.max_end.max(left.max_end);
+        }
+        if let Some(right) = &self.right {
+            self.max_end = self.max_end.max(right.max_end);
+        }
+    }
+}
+
+// Gets the upper point of the interval, closed after open.
+fn end<T: Item>(interval: &Interval<T>) -> (Bound<T>, bool) {
+    if interval.empty() {
+        return (Bound::NegInf, false);
+    }
+    (interval.upper, interval.right_closed())
+}
+
+fn height<T: Item, V>(link: &Link<T, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+fn rotate_left<T: Item, V>(mut node: Box<Node<T, V>>) -> Box<Node<T, V>> {
+    let mut right = node.right.take().unwrap();
+    node.right = right.left.take();
+    node.update();
+    right.left = Some(node);
+    right.update();
+    right
+}
+
+fn rotate_right<T: Item, V>(mut node: Box<Node<T, V>>) -> Box<Node<T, V>> {
+    let mut left = node.left.take().unwrap();
+    node.left = left.right.take();
+    node.update();
+    left.right = Some(node);
+    left.update();
+    left
+}
+
+// Restores the height balance of a node whose children are balanced.
+fn balance<T: Item, V>(mut node: Box<Node<T, V>>) -> Box<Node<T, V>> {
+    node.update();
+    let left = height(&node.left);
+    let right = height(&node.right);
+
+    if left > right + 1 {
+        let child = node.left.take().unwrap();
+        node.left = if height(&child.right) > height(&child.left) {
+            Some(rotate_left(child))
+        } else {
+            Some(child)
+        };
+        return rotate_right(node);
+    }
+
+    if right > left + 1 {
+        let child = node.right.take().unwrap();
+        node.right = if height(&child.left) > height(&child.right) {
+            Some(rotate_right(child))
+        } else {
+            Some(child)
+        };
+        return rotate_left(node);
+    }
+
+    node
+}
+
+fn insert<T: Item, V>(link: Link<T, V>, new: Box<Node<T, V>>) -> Box<Node<T, V>> {
+    let mut node = match link {
+        Some(node) => node,
+        None => return new,
+    };
+
+    if new.key() < node.key() {
+        node.left = Some(insert(node.left.take(), new));
+    } else {
+        node.right = Some(insert(node.right.take(), new));
+    }
+    balance(node)
+}
+
+// Finds the id of the earliest node with the interval.
+fn find<T: Item, V>(link: &Link<T, V>, interval: &Interval<T>) -> Option<u64> {
+    let node = link.as_ref()?;
+    match interval.cmp(&node.interval) {
+        Ordering::Less => find(&node.left, interval),
+        Ordering::Greater => find(&node.right, interval),
+        Ordering::Equal => find(&node.left, interval).or(Some(node.id)),
+    }
+}
+
+fn remove_min<T: Item, V>(mut node: Box<Node<T, V>>) -> (Link<T, V>, Box<Node<T, V>>) {
+    match node.left.take() {
+        Some(left) => {
+            let (rest, min) = remove_min(left);
+            node.left = rest;
+            (Some(balance(node)), min)
+        }
+        None => (node.right.take(), node),
+    }
+}
+
+fn remove<T: Item, V>(link: Link<T, V>, key: &(Interval<T>, u64)) -> (Link<T, V>, Option<V>) {
+    let mut node = match link {
+        Some(node) => node,
+        None => return (None, None),
+    };
+
+    match key.cmp(&node.key()) {
+        Ordering::Less => {
+            let (left, value) = remove(node.left.take(), key);
+            node.left = left;
+            (Some(balance(node)), value)
+        }
+        Ordering::Greater => {
+            let (right, value) = remove(node.right.take(), key);
+            node.right = right;
+            (Some(balance(node)), value)
+        }
+        Ordering::Equal => {
+            let Node { left, right, value, .. } = *node;
+            let link = match (left, right) {
+                (None, right) => right,
+                (left, None) => left,
+                (Some(left), Some(right)) => {
+                    let (rest, mut min) = remove_min(right);
+                    min.left = Some(left);
+                    min.right = rest;
+                    Some(balance(min))
+                }
+            };
+            (link, Some(value))
+        }
+    }
+}
+
+fn containing<'a, T: Item, V>(link: &'a Link<T, V>, value: &T, found: &mut Vec<(Interval<T>, &'a V)>) {
+    let node = match link {
+        Some(node) => node,
+        None => return,
+    };
+
+    // Nothing in the subtree reaches the point.
+    let (upper, closed) = node.max_end;
+    let point = Bound::Value(*value);
+    if upper < point || (upper == point && !closed) {
+        return;
+    }
+
+    containing(&node.left, value, found);
+    if node.interval.contains(value) {
+        found.push((node.interval, &node.value));
+    }
+
+    // Intervals on the right start no earlier than this one.
+    if node.interval.lower < point || (node.interval.lower == point && node.interval.left_closed()) {
+        containing(&node.right, value, found);
+    }
+}
+
+fn overlapping<'a, T: Item, V>(link: &'a Link<T, V>, interval: &Interval<T>, found: &mut Vec<(Interval<T>, &'a V)>) {
+    let node = match link {
+        Some(node) => node,
+        None => return,
+    };
+
+    // Nothing in the subtree reaches the interval.
+    let (upper, closed) = node.max_end;
+    if upper < interval.lower || (upper == interval.lower && !(closed && interval.left_closed())) {
+        return;
+    }
+
+    overlapping(&node.left, interval, found);
+    if node.interval.overlaps(interval) {
+        found.push((node.interval, &node.value));
+    }
+
+    // Intervals on the right start no earlier than this one.
+    let lower = node.interval.lower;
+    if lower < interval.upper || (lower == interval.upper && node.interval.left_closed() && interval.right_closed()) {
+        overlapping(&node.right, interval, found);
+    }
+}
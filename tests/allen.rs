@@ -0,0 +1,40 @@
+use portion_rs::AllenRelation::*;
+use portion_rs::*;
+
+fn check(x: Interval<i32>, y: Interval<i32>, relation: AllenRelation) {
+    assert_eq!(x.relation(&y), Some(relation));
+    assert_eq!(y.relation(&x), Some(relation.inverse()));
+}
+
+#[test]
+fn relation_closed() {
+    check(Portion::closed(1, 2), Portion::closed(4, 5), Before);
+    check(Portion::closed(1, 3), Portion::closed(2, 5), Overlaps);
+    check(Portion::closed(1, 3), Portion::closed(1, 5), Starts);
+    check(Portion::closed(2, 3), Portion::closed(1, 5), During);
+    check(Portion::closed(3, 5), Portion::closed(1, 5), Finishes);
+    check(Portion::closed(1, 5), Portion::closed(1, 5), Equals);
+}
+
+#[test]
+fn relation_bounds() {
+    check(Portion::closedopen(1, 2), Portion::closed(2, 3), Meets);
+    check(Portion::closed(1, 2), Portion::open(2, 3), Meets);
+    check(Portion::closedopen(1, 2), Portion::openclosed(2, 3), Before);
+    check(Portion::closed(1, 2), Portion::closed(2, 3), Overlaps);
+    check(Portion::open(1, 5), Portion::closed(1, 5), During);
+    check(Portion::openclosed(1, 5), Portion::closed(1, 5), Finishes);
+    check(Portion::closedopen(1, 5), Portion::closed(1, 5), Starts);
+    check(Portion::closed(1, 3), Portion::open(1, 5), Overlaps);
+}
+
+#[test]
+fn relation_special() {
+    check(Portion::singleton(1), Portion::closed(1, 3), Starts);
+    check(Portion::singleton(2), Portion::closed(1, 3), During);
+    check(Portion::singleton(1), Portion::openclosed(1, 3), Meets);
+    check(Portion::singleton(2), Portion::singleton(2), Equals);
+    check(Portion::inf_open(0), Portion::all(), Starts);
+    check(Portion::inf_open(0), Portion::closedopen_inf(0), Meets);
+    assert_eq!(Portion::closed(1, 2).relation(&Portion::empty()), None);
+}
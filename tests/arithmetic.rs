@@ -0,0 +1,91 @@
+use portion_rs::*;
+
+#[test]
+fn translate() {
+    assert_eq!("[33, 41]", (Portion::closed(9, 17) + 24).to_string());
+    assert_eq!("(-15, -7]", (Portion::openclosed(9, 17) - 24).to_string());
+    assert_eq!("[5]", (Portion::singleton(3) + 2).to_string());
+    assert_eq!("(-inf, 3)", (Portion::inf_open(1) + 2).to_string());
+    assert_eq!("[-1, +inf)", (Portion::closedopen_inf(1) - 2).to_string());
+    assert_eq!("()", (Portion::empty() + 2).to_string());
+}
+
+#[test]
+fn add_intervals() {
+    let x = Portion::closed(1, 2).sum(&Portion::closed(3, 4));
+    assert_eq!("[4, 6]", x.to_string());
+    let x = Portion::closedopen(1, 2).sum(&Portion::closed(3, 4));
+    assert_eq!("[4, 6)", x.to_string());
+    let x = Portion::open(1, 2).sum(&Portion::closedopen_inf(0));
+    assert_eq!("(1, +inf)", x.to_string());
+    assert_eq!("()", Portion::closed(1, 2).sum(&Portion::empty()).to_string());
+}
+
+#[test]
+fn multiply() {
+    assert_eq!(
+        "[3, 8]",
+        Portion::closed(1, 2).product(&Portion::closed(3, 4)).to_string()
+    );
+    assert_eq!(
+        "[-8, 8]",
+        Portion::closed(-2, 2).product(&Portion::closed(-3, 4)).to_string()
+    );
+    assert_eq!(
+        "(-4, -2]",
+        Portion::closedopen(1, 2).product(&Portion::singleton(-2)).to_string()
+    );
+    assert_eq!("[-6, -2]", (Portion::closed(1, 3) * -2).to_string());
+    assert_eq!(
+        "(-1, 1)",
+        Portion::open(-1, 1).product(&Portion::open(-1, 1)).to_string()
+    );
+    assert_eq!("[0]", Portion::open(-1, 1).product(&Portion::singleton(0)).to_string());
+    assert_eq!(
+        "[0, +inf)",
+        Portion::closed(0, 1).product(&Portion::closedopen_inf(1)).to_string()
+    );
+    assert_eq!(
+        "(0, +inf)",
+        Portion::openclosed(0, 1)
+            .product(&Portion::closedopen_inf(1))
+            .to_string()
+    );
+    assert_eq!(
+        "(-inf, +inf)",
+        Portion::closed(-1, 1).product(&Portion::closedopen_inf(1)).to_string()
+    );
+    assert_eq!("()", Portion::closed(1, 2).product(&Portion::empty()).to_string());
+}
+
+#[test]
+fn overflow() {
+    assert_eq!(
+        "[250, 255]",
+        Portion::closed(250u8, 255).sum(&Portion::closed(0, 10)).to_string()
+    );
+    assert_eq!("[255]", (Portion::closed(250u8, 255) + 10).to_string());
+    assert_eq!("(251, 255]", (Portion::open(250u8, 255) + 1).to_string());
+    assert_eq!("[0, 2]", (Portion::closed(0u8, 5) - 3).to_string());
+    assert_eq!("[0]", (Portion::closedopen(0u8, 2) - 5).to_string());
+    assert_eq!("[-128, 127]", (Portion::closed(-64i8, 64) * 2).to_string());
+    assert_eq!("[-127, 127]", (Portion::closed(i8::MIN, 127) * -1).to_string());
+    assert_eq!("(-inf, 127]", (Portion::inf_openclosed(100i8) + 100).to_string());
+}
+
+#[test]
+fn float_arithmetic() {
+    let x = Portion::closed(OrderedFloat(0.5), OrderedFloat(1.5)) * OrderedFloat(2.0);
+    assert_eq!("[1, 3]", x.to_string());
+    let x =
+        Portion::closedopen(OrderedFloat(-0.0), OrderedFloat(1.0)).product(&Portion::closedopen_inf(OrderedFloat(1.0)));
+    assert_eq!("[-0, +inf)", x.to_string());
+}
+
+#[test]
+fn sum_and_difference() {
+    let x = Portion::closed(1, 4);
+    let y = Portion::closed(2, 4);
+    assert_eq!("[3, 8]", x.sum(&y).to_string());
+    assert_eq!("[1, 2)", (x - y).to_string());
+}
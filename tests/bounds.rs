@@ -0,0 +1,59 @@
+use portion_rs::*;
+
+#[test]
+fn finite() {
+    let x = Portion::closedopen(2, 5);
+    assert_eq!(x.left(), BoundKind::Closed);
+    assert_eq!(x.lower_bound(), Bound::Value(2));
+    assert_eq!(x.upper_bound(), Bound::Value(5));
+    assert_eq!(x.right(), BoundKind::Open);
+    assert!(x.left_closed() && x.right_open());
+}
+
+#[test]
+fn infinite() {
+    let x = Portion::inf_openclosed(5);
+    assert_eq!(x.left(), BoundKind::Open);
+    assert_eq!(x.lower_bound(), Bound::NegInf);
+    assert_eq!(x.lower_bound().value(), None);
+    assert_eq!(x.upper_bound().value(), Some(5));
+    assert_eq!(x.right(), BoundKind::Closed);
+}
+
+#[test]
+fn singleton() {
+    let x = Portion::singleton(3);
+    assert!(x.singleton());
+    assert_eq!(x.left(), BoundKind::Closed);
+    assert_eq!(x.lower_bound(), x.upper_bound());
+    assert_eq!(x.right(), BoundKind::Closed);
+}
+
+#[test]
+fn empty() {
+    let x: Interval<i32> = Portion::empty();
+    assert_eq!(x.left(), BoundKind::Open);
+    assert_eq!(x.lower_bound(), Bound::PosInf);
+    assert_eq!(x.upper_bound(), Bound::NegInf);
+    assert_eq!(x.right(), BoundKind::Open);
+}
+
+#[test]
+fn empty_from_points() {
+    let x = Portion::closed(5, 3);
+    assert_eq!(x.left(), BoundKind::Open);
+    assert_eq!(x.lower_bound(), Bound::PosInf);
+    assert_eq!(x.upper_bound(), Bound::NegInf);
+    assert_eq!(x.right(), BoundKind::Open);
+    assert!(x.left_open() && x.right_open());
+    assert!(!x.left_closed() && !x.right_closed());
+    assert!(!x.singleton());
+    assert!(!Portion::closedopen(1, 1).singleton());
+}
+
+#[test]
+fn singleton_from_points() {
+    let x = Portion::closed(1, 1);
+    assert!(x.singleton());
+    assert_eq!("[1]", x.to_string());
+}
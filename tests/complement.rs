@@ -0,0 +1,67 @@
+use portion_rs::*;
+
+#[test]
+fn complement_atomic() {
+    let x = Portion::closedopen(2, 5);
+    assert_eq!("(-inf, 2) | [5, +inf)", (!x).to_string());
+
+    let x = Portion::singleton(3);
+    assert_eq!("(-inf, 3) | (3, +inf)", (!x).to_string());
+
+    let x = Portion::inf_openclosed(4);
+    assert_eq!("(4, +inf)", (!x).to_string());
+}
+
+#[test]
+fn complement_trivial() {
+    let x: Interval<i32> = Portion::empty();
+    assert_eq!("(-inf, +inf)", (!x).to_string());
+
+    let x: Interval<i32> = Portion::all();
+    assert_eq!("()", (!x).to_string());
+}
+
+#[test]
+fn complement_set() {
+    let x = Portion::closed(1, 2) | Portion::open(4, 5);
+    assert_eq!("(-inf, 1) | (2, 4] | [5, +inf)", (!x).to_string());
+
+    let x = Portion::closed(1, 2) | Portion::open(4, 5);
+    assert_eq!(x.clone(), !!x);
+}
+
+#[test]
+fn difference() {
+    let x = Portion::closed(9, 17);
+    let y = Portion::closedopen(12, 13);
+    assert_eq!("[9, 12) | [13, 17]", (x - y).to_string());
+
+    let x = Portion::closed(1, 3);
+    let y = Portion::closed(0, 5);
+    assert_eq!("()", (x - y).to_string());
+
+    let x = Portion::closed(9, 17);
+    let y = Portion::closed(9, 10) | Portion::open(14, 15);
+    assert_eq!("(10, 14] | [15, 17]", (x - y).to_string());
+    assert_eq!(
+        "(10, 14] | [15, 17]",
+        x.difference(Portion::closed(9, 10))
+            .difference(Portion::open(14, 15).into())
+            .to_string()
+    );
+}
+
+#[test]
+fn symmetric_difference() {
+    let x = Portion::closed(1, 5);
+    let y = Portion::closed(3, 8);
+    assert_eq!("[1, 3) | (5, 8]", (x ^ y).to_string());
+
+    let x = Portion::closed(1, 5);
+    let y = Portion::open(1, 5);
+    assert_eq!("[1] | [5]", (x ^ y).to_string());
+
+    let x = Portion::closed(1, 2) | Portion::closed(4, 6);
+    let y = Portion::closed(2, 5);
+    assert_eq!("[1, 2) | (2, 4) | (5, 6]", x.symmetric_difference(y.into()).to_string());
+}
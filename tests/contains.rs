@@ -0,0 +1,53 @@
+use portion_rs::*;
+
+#[test]
+fn contains_value() {
+    let x = Portion::closedopen(2, 5);
+    assert!(x.contains(&2));
+    assert!(x.contains(&4));
+    assert!(!x.contains(&5));
+    assert!(!x.contains(&1));
+
+    let x = Portion::openclosed(0, u64::MAX);
+    assert!(x.contains(&u64::MAX));
+    assert!(!x.contains(&0));
+
+    let x: Interval<i32> = Portion::empty();
+    assert!(!x.contains(&0));
+}
+
+#[test]
+fn contains_infinite() {
+    let x = Portion::inf_openclosed(5);
+    assert!(x.contains(&i64::MIN));
+    assert!(x.contains(&5));
+    assert!(!x.contains(&6));
+    assert!(Portion::all().contains(&0));
+}
+
+#[test]
+fn contains_interval() {
+    let x = Portion::closed(1, 5);
+    assert!(x.contains_interval(&Portion::open(1, 5)));
+    assert!(x.contains_interval(&Portion::closed(1, 5)));
+    assert!(x.contains_interval(&Portion::singleton(5)));
+    assert!(x.contains_interval(&Portion::empty()));
+    assert!(!x.contains_interval(&Portion::closed(0, 2)));
+
+    let x = Portion::open(1, 5);
+    assert!(!x.contains_interval(&Portion::closed(1, 5)));
+    assert!(!x.contains_interval(&Portion::singleton(1)));
+}
+
+#[test]
+fn contains_set() {
+    let x = Portion::closed(1, 2) | Portion::open(4, 6) | Portion::singleton(8);
+    assert!(x.contains(&1));
+    assert!(!x.contains(&3));
+    assert!(!x.contains(&4));
+    assert!(x.contains(&5));
+    assert!(x.contains(&8));
+    assert!(!x.contains(&9));
+    assert!(x.contains_interval(&Portion::closedopen(5, 6)));
+    assert!(!x.contains_interval(&Portion::closed(2, 5)));
+}
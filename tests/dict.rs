@@ -0,0 +1,108 @@
+use portion_rs::*;
+
+#[test]
+fn dict_insert() {
+    let mut dict = IntervalDict::new();
+    dict.insert(Portion::closed(2, 6), "a");
+    dict.insert(Portion::closed(4, 8), "b");
+    assert_eq!("{[2, 4): a, [4, 8]: b}", dict.to_string());
+    assert_eq!(Some(&"a"), dict.get(&3));
+    assert_eq!(Some(&"b"), dict.get(&4));
+
+    dict.insert(Portion::open(5, 6), "c");
+    assert_eq!("{[2, 4): a, [4, 5]: b, (5, 6): c, [6, 8]: b}", dict.to_string());
+    assert_eq!(dict.len(), 4);
+
+    dict.insert(Portion::inf_open(3) | Portion::singleton(7), "d");
+    assert_eq!(
+        "{(-inf, 3): d, [3, 4): a, [4, 5]: b, (5, 6): c, [6, 7): b, [7]: d, (7, 8]: b}",
+        dict.to_string()
+    );
+}
+
+#[test]
+fn dict_get() {
+    let dict: IntervalDict<i32, &str> = vec![(Portion::closedopen(0, 5), "a"), (Portion::open(5, 9), "b")]
+        .into_iter()
+        .collect();
+    assert_eq!(dict.get(&0), Some(&"a"));
+    assert_eq!(dict.get(&4), Some(&"a"));
+    assert_eq!(dict.get(&5), None);
+    assert_eq!(dict.get(&6), Some(&"b"));
+    assert_eq!(dict.get(&9), None);
+    assert_eq!(dict.get(&-1), None);
+
+    let dict: IntervalDict<i32, &str> = vec![
+        (Portion::closedopen(0, 4), "a"),
+        (Portion::closed(4, 8), "b"),
+        (Portion::open(8, 9), "c"),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(dict.get(&4), Some(&"b"));
+    assert_eq!(dict.get(&8), Some(&"b"));
+    assert_eq!(dict.get(&9), None);
+}
+
+#[test]
+fn dict_get_interval() {
+    let mut dict = IntervalDict::new();
+    dict.insert(Portion::closed(0, 4), 1);
+    dict.insert(Portion::closed(6, 9), 2);
+
+    let sub = dict.get_interval(Portion::open(3, 7));
+    assert_eq!("{(3, 4]: 1, [6, 7): 2}", sub.to_string());
+    assert!(dict.get_interval(Portion::open(4, 6)).is_empty());
+}
+
+#[test]
+fn dict_remove_domain() {
+    let mut dict = IntervalDict::new();
+    dict.insert(Portion::closed(0, 9), 'x');
+    dict.remove(Portion::closed(3, 5));
+    assert_eq!("[0, 3) | (5, 9]", dict.domain().to_string());
+
+    let pairs: Vec<(Interval<i32>, char)> = dict.into_iter().collect();
+    assert_eq!(
+        pairs,
+        vec![(Portion::closedopen(0, 3), 'x'), (Portion::openclosed(5, 9), 'x')]
+    );
+}
+
+#[test]
+fn dict_combine() {
+    let mut x = IntervalDict::new();
+    x.insert(Portion::closed(0, 4), 1);
+    let mut y = IntervalDict::new();
+    y.insert(Portion::closed(2, 6), 10);
+
+    let z = x.combine(&y, |a, b| a + b);
+    assert_eq!("{[0, 2): 1, [2, 4]: 11, (4, 6]: 10}", z.to_string());
+
+    let pairs: Vec<(Interval<i32>, &i32)> = z.iter().collect();
+    assert_eq!(pairs[1], (Portion::closed(2, 4), &11));
+}
+
+#[test]
+fn dict_merge_equal() {
+    let mut x = IntervalDict::new();
+    x.insert(Portion::closedopen(0, 2), "a");
+    x.insert(Portion::closed(2, 4), "a");
+    x.insert(Portion::closed(3, 6), "b");
+    let y: IntervalDict<i32, &str> = vec![(Portion::closedopen(0, 3), "a"), (Portion::closed(3, 6), "b")]
+        .into_iter()
+        .collect();
+    assert_eq!(x, y);
+    assert_eq!("{[0, 3): a, [3, 6]: b}", x.to_string());
+
+    x.insert(Portion::open(2, 7), "a");
+    assert_eq!("{[0, 7): a}", x.to_string());
+    assert_eq!(x.len(), 1);
+
+    let mut z = IntervalDict::new();
+    z.insert(Portion::closed(0, 2), 1);
+    z.insert(Portion::open(2, 4), 2);
+    let w = z.combine(&z.get_interval(Portion::closed(3, 4)), |a, _| a - 1);
+    assert_eq!("{[0, 2]: 1, (2, 4): 2}", z.to_string());
+    assert_eq!("{[0, 2]: 1, (2, 3): 2, [3, 4): 1}", w.to_string());
+}
@@ -0,0 +1,33 @@
+use portion_rs::*;
+
+#[test]
+fn discrete_interval() {
+    assert_eq!(Portion::open(1, 5).discrete(), Portion::closed(2, 4));
+    assert_eq!(Portion::closedopen(1, 5).discrete(), Portion::closed(1, 4));
+    assert_eq!(Portion::open(1, 3).discrete(), Portion::singleton(2));
+    assert_eq!(Portion::open(1, 2).discrete(), Portion::empty());
+    assert_eq!(Portion::open(0u8, 0).discrete(), Portion::empty());
+    assert_eq!("[1, 2]", Portion::openclosed(0u8, 2).discrete().to_string());
+}
+
+#[test]
+fn discrete_boundaries() {
+    assert_eq!(Portion::openclosed(u8::MAX, u8::MAX).discrete(), Portion::empty());
+    assert_eq!(Portion::closedopen(i8::MIN, i8::MIN).discrete(), Portion::empty());
+    assert_eq!("[-128, 4]", Portion::inf_open(5i8).discrete().to_string());
+    assert_eq!("[6, 255]", Portion::open_inf(5u8).discrete().to_string());
+    assert_eq!(Portion::<u8>::all().discrete(), Portion::closed(0u8, 255).discrete());
+}
+
+#[test]
+fn discrete_set() {
+    let x = (Portion::closed(1, 2) | Portion::closed(3, 4)).discrete();
+    assert_eq!("[1, 4]", x.to_string());
+
+    let x = (Portion::closedopen(1, 3) | Portion::open(3, 6) | Portion::closed(8, 9)).discrete();
+    assert_eq!("[1, 2] | [4, 5] | [8, 9]", x.to_string());
+
+    let x = (Portion::inf_openclosed(2i8) | Portion::closedopen_inf(3)).discrete();
+    assert_eq!("[-128, 127]", x.to_string());
+    assert_eq!(x, IntervalSet::from(Portion::all()).discrete());
+}
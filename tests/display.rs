@@ -35,3 +35,15 @@ fn display_closedopen() {
     let x = Portion::closedopen(4, 9);
     assert_eq!("[4, 9)", x.to_string())
 }
+
+#[test]
+fn display_infinite() {
+    let x: Interval<i32> = Portion::all();
+    assert_eq!("(-inf, +inf)", x.to_string());
+
+    let x = Portion::inf_openclosed(5);
+    assert_eq!("(-inf, 5]", x.to_string());
+
+    let x = Portion::closedopen_inf(3);
+    assert_eq!("[3, +inf)", x.to_string());
+}
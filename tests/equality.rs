@@ -0,0 +1,38 @@
+use portion_rs::*;
+use std::collections::{BTreeSet, HashMap};
+
+#[test]
+fn equality() {
+    assert_eq!(Portion::closed(3, 3), Portion::singleton(3));
+    assert_eq!(Portion::closed(5, 2), Portion::empty());
+    assert_eq!(Portion::open(4, 4), Portion::closedopen(9, 1));
+    assert_ne!(Portion::open(1, 5), Portion::closed(1, 5));
+    assert_ne!(Portion::closedopen(1, 5), Portion::openclosed(1, 5));
+}
+
+#[test]
+fn hash() {
+    let mut map = HashMap::new();
+    map.insert(Portion::closed(1, 1), "one");
+    map.insert(Portion::open(2, 2), "empty");
+    assert_eq!(map.get(&Portion::singleton(1)), Some(&"one"));
+    assert_eq!(map.get(&Portion::empty()), Some(&"empty"));
+    assert_eq!(map.get(&Portion::open(1, 1)), Some(&"empty"));
+}
+
+#[test]
+fn ordering() {
+    let set: BTreeSet<Interval<i32>> = vec![
+        Portion::closed(2, 4),
+        Portion::open(1, 4),
+        Portion::closed(1, 4),
+        Portion::closedopen(1, 4),
+        Portion::inf_open(0),
+        Portion::empty(),
+    ]
+    .into_iter()
+    .collect();
+
+    let sorted: Vec<String> = set.iter().map(|x| x.to_string()).collect();
+    assert_eq!(sorted, vec!["(-inf, 0)", "[1, 4)", "[1, 4]", "(1, 4)", "[2, 4]", "()"]);
+}
@@ -0,0 +1,31 @@
+use portion_rs::*;
+
+fn f(value: f64) -> OrderedFloat<f64> {
+    OrderedFloat(value)
+}
+
+#[test]
+fn float_operations() {
+    let x = Portion::closed(f(0.5), f(2.5));
+    let y = Portion::open(f(1.5), f(3.0));
+    assert_eq!("(1.5, 2.5]", (x & y).to_string());
+    assert_eq!("[0.5, 3)", (x | y).to_string());
+    assert_eq!("[0.5, 1.5]", (x - y).to_string());
+    assert_eq!("[0.5, 1.5] | (2.5, 3)", (x ^ y).to_string());
+}
+
+#[test]
+fn float_contains() {
+    let x = Portion::closedopen(f(-1.0), f(1.0));
+    assert!(x.contains(&f(-1.0)));
+    assert!(x.contains(&f(0.999)));
+    assert!(!x.contains(&f(1.0)));
+    assert!(Portion::closedopen_inf(f(0.0)).contains(&f(f64::INFINITY)));
+}
+
+#[test]
+fn float_f32() {
+    let x = Portion::singleton(OrderedFloat(0.25f32));
+    assert_eq!("[0.25]", x.to_string());
+    assert_eq!("(-inf, 0.25) | (0.25, +inf)", (!x).to_string());
+}
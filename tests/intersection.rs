@@ -35,3 +35,29 @@ fn intersection_variety() {
     let y = Portion::open(2, 4);
     assert_eq!("[3, 4)", (x & y).to_string());
 }
+
+#[test]
+fn intersection_touching() {
+    let x = Portion::closed(1, 3);
+    let y = Portion::closed(3, 5);
+    assert_eq!("[3]", (x & y).to_string());
+
+    let x = Portion::closed(1, 4);
+    let y = Portion::open(2, 6);
+    assert_eq!("(2, 4]", (x & y).to_string());
+}
+
+#[test]
+fn intersection_infinite() {
+    let x = Portion::inf_openclosed(5);
+    let y = Portion::closedopen_inf(3);
+    assert_eq!("[3, 5]", (x & y).to_string());
+
+    let x = Portion::all();
+    let y = Portion::openclosed(1, 2);
+    assert_eq!("(1, 2]", (x & y).to_string());
+
+    let x = Portion::inf_open(3);
+    let y = Portion::closedopen_inf(3);
+    assert_eq!("()", (x & y).to_string());
+}
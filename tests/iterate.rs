@@ -0,0 +1,93 @@
+use portion_rs::*;
+
+fn f(value: f64) -> OrderedFloat<f64> {
+    OrderedFloat(value)
+}
+
+fn floats<I: Iterator<Item = OrderedFloat<f64>>>(iter: I) -> Vec<f64> {
+    iter.map(|x| x.0).collect()
+}
+
+#[test]
+fn continuous() {
+    let x = Portion::closedopen(f(0.0), f(2.0));
+    assert_eq!(floats(x.iterate(|x| f(x.0 + 0.5))), vec![0.0, 0.5, 1.0, 1.5]);
+
+    let x = Portion::open(f(0.0), f(2.0));
+    assert_eq!(floats(x.iterate(|x| f(x.0 + 0.5))), vec![0.5, 1.0, 1.5]);
+}
+
+#[test]
+fn base() {
+    let x = Portion::closed(f(0.3), f(2.0));
+    let values = x.iterate(|x| f(x.0 + 0.5)).base(|x| f(x.0.floor()));
+    assert_eq!(floats(values), vec![0.5, 1.0, 1.5, 2.0]);
+
+    let values = x.iterate(|x| f(x.0 + 0.5)).base(|x| f(x.0.ceil()));
+    assert_eq!(floats(values), vec![1.0, 1.5, 2.0]);
+}
+
+#[test]
+fn reverse() {
+    let x = Portion::openclosed(f(0.0), f(2.0));
+    assert_eq!(floats(x.iterate_rev(|x| f(x.0 - 0.5))), vec![2.0, 1.5, 1.0, 0.5]);
+
+    let x = Portion::closed(f(0.0), f(1.7));
+    let values = x.iterate_rev(|x| f(x.0 - 0.5)).base(|x| f(x.0.floor()));
+    assert_eq!(floats(values), vec![1.0, 0.5, 0.0]);
+}
+
+#[test]
+fn set() {
+    let x = Portion::closed(f(0.0), f(1.0)) | Portion::closed(f(3.0), f(4.0));
+    assert_eq!(floats(x.iterate(|x| f(x.0 + 1.5))), vec![0.0, 3.0]);
+    assert_eq!(floats(x.iterate(|x| f(x.0 + 0.5))), vec![0.0, 0.5, 1.0, 3.0, 3.5, 4.0]);
+    assert_eq!(floats(x.iterate_rev(|x| f(x.0 - 1.0))), vec![4.0, 3.0, 1.0, 0.0]);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Date {
+    year: u32,
+    month: u32,
+}
+
+fn next_month(date: Date) -> Date {
+    if date.month == 12 {
+        Date {
+            year: date.year + 1,
+            month: 1,
+        }
+    } else {
+        Date {
+            month: date.month + 1,
+            ..date
+        }
+    }
+}
+
+#[test]
+fn dates() {
+    let start = Date { year: 2020, month: 11 };
+    let end = Date { year: 2021, month: 2 };
+    let x: Vec<Date> = Portion::closed(start, end).iterate(next_month).collect();
+    assert_eq!(x.len(), 4);
+    assert_eq!(x[2], Date { year: 2021, month: 1 });
+}
+
+#[test]
+fn unbounded_and_empty() {
+    let x: Vec<u32> = Portion::closedopen_inf(5).iterate(|x| x * 2).take(3).collect();
+    assert_eq!(x, vec![5, 10, 20]);
+    assert_eq!(Portion::<u32>::empty().iterate(|x| x + 1).next(), None);
+}
+
+#[test]
+fn infinite_start() {
+    assert_eq!(Portion::inf_open(5).iterate(|x| x + 1).next(), None);
+    assert_eq!(Portion::closedopen_inf(5).iterate_rev(|x| x - 1).next(), None);
+    assert_eq!(
+        (Portion::inf_open(0) | Portion::closed(3, 4)).iterate(|x| x + 1).next(),
+        None
+    );
+    assert_eq!(Portion::closedopen_inf(5).iterate(|x| x + 1).nth(2), Some(7));
+}
@@ -35,3 +35,128 @@ fn empty() {
     let x: Vec<u8> = Portion::empty().into_iter().collect();
     assert_eq!(x, vec![]);
 }
+
+#[test]
+fn infinite() {
+    let x: Vec<u8> = Portion::inf_openclosed(3).into_iter().collect();
+    assert_eq!(x, vec![0, 1, 2, 3]);
+
+    let x: Vec<u8> = Portion::open_inf(252).into_iter().collect();
+    assert_eq!(x, vec![253, 254, 255]);
+
+    let x: Vec<i8> = Portion::all().into_iter().collect();
+    assert_eq!(x.len(), 256);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Weekday(u8);
+
+impl Discrete for Weekday {
+    fn next(self) -> Self {
+        Weekday(self.0 + 1)
+    }
+
+    fn prev(self) -> Self {
+        Weekday(self.0 - 1)
+    }
+
+    fn minimum() -> Self {
+        Weekday(0)
+    }
+
+    fn maximum() -> Self {
+        Weekday(6)
+    }
+}
+
+#[test]
+fn custom() {
+    let x: Vec<Weekday> = Portion::inf_open(Weekday(3)).into_iter().collect();
+    assert_eq!(x, vec![Weekday(0), Weekday(1), Weekday(2)]);
+
+    let x: Vec<Weekday> = Portion::openclosed(Weekday(4), Weekday(6)).into_iter().collect();
+    assert_eq!(x, vec![Weekday(5), Weekday(6)]);
+}
+
+#[test]
+fn reverse() {
+    let x: Vec<u8> = Portion::closedopen(2, 5).into_iter().rev().collect();
+    assert_eq!(x, vec![4, 3, 2]);
+
+    let x: Vec<u8> = Portion::openclosed(2, 5).rev().collect();
+    assert_eq!(x, vec![5, 4, 3]);
+
+    let x: Vec<u8> = Portion::open_inf(252).rev().collect();
+    assert_eq!(x, vec![255, 254, 253]);
+
+    let x: Vec<i8> = Portion::inf_open(-126).rev().collect();
+    assert_eq!(x, vec![-127, -128]);
+}
+
+#[test]
+fn both_ends() {
+    let mut x = Portion::closed(1, 4).into_iter();
+    assert_eq!(x.next(), Some(1));
+    assert_eq!(x.next_back(), Some(4));
+    assert_eq!(x.next_back(), Some(3));
+    assert_eq!(x.next(), Some(2));
+    assert_eq!(x.next(), None);
+    assert_eq!(x.next_back(), None);
+}
+
+#[test]
+fn exact_size() {
+    assert_eq!(Portion::open(2u8, 10).iter().len(), 7);
+    assert_eq!(Portion::<u8>::empty().iter().len(), 0);
+    assert_eq!(Portion::<u8>::all().iter().len(), 256);
+    assert_eq!(Portion::<i16>::all().iter().len(), 65536);
+    assert_eq!(Portion::<u64>::all().iter().size_hint(), (usize::MAX, None));
+
+    let mut x = Portion::closed(Weekday(1), Weekday(5)).iter();
+    assert_eq!(x.size_hint(), (5, Some(5)));
+    x.next_back();
+    assert_eq!(x.size_hint(), (4, Some(4)));
+}
+
+#[test]
+fn borrowed() {
+    let x = Portion::closed(1, 3);
+    let mut total = 0;
+    for value in &x {
+        total += value;
+    }
+    assert_eq!(total, 6);
+    assert_eq!(x.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+}
+
+#[test]
+fn step() {
+    let x: Vec<u32> = Portion::closedopen(0, 100).iter_step(5).collect();
+    assert_eq!(x.len(), 20);
+    assert_eq!(x[19], 95);
+
+    let x: Vec<u32> = Portion::open(0, 10).iter_step(3).collect();
+    assert_eq!(x, vec![1, 4, 7]);
+
+    let x: Vec<u8> = Portion::closed(0, 10).iter_step(3).rev().collect();
+    assert_eq!(x, vec![9, 6, 3, 0]);
+}
+
+#[test]
+fn nth() {
+    let mut x = Portion::closed(1, 10).iter();
+    assert_eq!(x.nth(2), Some(3));
+    assert_eq!(x.nth_back(2), Some(8));
+    assert_eq!(x.nth(4), None);
+    assert_eq!(x.next(), None);
+
+    let x: Vec<i32> = Portion::all().iter_step(1 << 30).collect();
+    assert_eq!(x, vec![i32::MIN, -(1 << 30), 0, 1 << 30]);
+
+    let x: Vec<i64> = Portion::all().rev().step_by(1 << 30).take(2).collect();
+    assert_eq!(x, vec![i64::MAX, i64::MAX - (1 << 30)]);
+
+    let mut x = Portion::closed(Weekday(0), Weekday(6)).iter();
+    assert_eq!(x.nth(3), Some(Weekday(3)));
+    assert_eq!(x.nth_back(3), None);
+}
@@ -0,0 +1,54 @@
+use portion_rs::*;
+
+fn f(value: f64) -> OrderedFloat<f64> {
+    OrderedFloat(value)
+}
+
+#[test]
+fn count() {
+    assert_eq!(Some(4), Portion::closed(1, 4).count());
+    assert_eq!(Some(2), Portion::open(1, 4).count());
+    assert_eq!(Some(1), Portion::singleton(7).count());
+    assert_eq!(Some(0), Portion::open(1, 2).count());
+    assert_eq!(Some(0), Portion::<i32>::empty().count());
+    assert_eq!(Some(255), Portion::closedopen(0u8, 255).count());
+    assert_eq!(Some(256), Portion::closed(0u8, 255).count());
+    assert_eq!(Some(128), Portion::closedopen_inf(0i8).count());
+    assert_eq!(Some(1_000_001), Portion::closed(0u64, 1_000_000).count());
+    assert_eq!(None, Portion::<u128>::all().count());
+}
+
+#[test]
+fn length() {
+    assert_eq!(Bound::Value(f(1.5)), Portion::open(f(0.5), f(2.0)).length());
+    assert_eq!(Bound::Value(f(0.0)), Portion::singleton(f(3.0)).length());
+    assert_eq!(Bound::Value(f(0.0)), Portion::<OrderedFloat<f64>>::empty().length());
+    assert_eq!(Bound::PosInf, Portion::inf_open(f(0.0)).length());
+    assert_eq!(Bound::Value(3), Portion::closedopen(1, 4).length());
+    assert_eq!(Bound::PosInf, Portion::closed(-100i8, 100).length());
+}
+
+#[test]
+fn measure() {
+    let x = Portion::closed(f(0.0), f(1.0)) | Portion::open(f(2.0), f(2.5));
+    assert_eq!(Bound::Value(f(1.5)), x.measure());
+    assert_eq!(Bound::PosInf, (!x).measure());
+    assert_eq!(
+        Bound::Value(f(0.0)),
+        IntervalSet::<OrderedFloat<f64>>::from(Portion::empty()).measure()
+    );
+}
+
+#[test]
+fn set_count() {
+    let x = Portion::closed(1, 3) | Portion::open(5, 9);
+    assert_eq!(Some(6), x.count());
+    assert_eq!(
+        Some(256 - 6),
+        (!(Portion::closed(1u8, 3) | Portion::open(5, 9))).count()
+    );
+    assert_eq!(
+        None,
+        (Portion::closed(0u128, 1) | Portion::closed(5, u128::MAX)).count()
+    );
+}
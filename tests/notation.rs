@@ -0,0 +1,85 @@
+use portion_rs::*;
+
+#[test]
+fn notation_default() {
+    let x = Portion::closedopen(1, 2) | Portion::open_inf(3);
+    assert_eq!(x.to_string(), Notation::default().format_set(&x));
+    assert_eq!(Ok(x.clone()), Notation::default().parse_set(&x.to_string()));
+}
+
+#[test]
+fn notation_iso() {
+    let notation = Notation {
+        separator: "..",
+        ..Notation::default()
+    };
+
+    let x = Portion::closedopen(OrderedFloat(1.5), OrderedFloat(2.5));
+    assert_eq!("[1.5..2.5)", notation.format(&x));
+    assert_eq!(Ok(x), notation.parse("[1.5..2.5)"));
+    assert_eq!(Ok(Portion::singleton(-3)), notation.parse("[-3]"));
+}
+
+#[test]
+fn notation_reversed() {
+    let notation = Notation {
+        left_open: "]",
+        right_open: "[",
+        ..Notation::default()
+    };
+
+    let x = Portion::open(1, 4) | Portion::closed(6, 8) | Portion::singleton(10);
+    assert_eq!("]1, 4[ | [6, 8] | [10]", notation.format_set(&x));
+    assert_eq!(Ok(x), notation.parse_set("]1, 4[ | [6, 8] | [10]"));
+}
+
+#[test]
+fn notation_tokens() {
+    let notation = Notation {
+        empty: "{}",
+        neg_inf: "-oo",
+        pos_inf: "oo",
+        union: " or ",
+        ..Notation::default()
+    };
+
+    let x: Interval<i32> = Portion::empty();
+    assert_eq!("{}", notation.format(&x));
+    assert_eq!(Ok(x), notation.parse("{}"));
+
+    let x = Portion::inf_open(0) | Portion::open_inf(0);
+    assert_eq!("(-oo, 0) or (0, oo)", notation.format_set(&x));
+    assert_eq!(Ok(x), notation.parse_set("(-oo, 0) or (0, oo)"));
+
+    let error = notation.parse::<i32>("(-oo, 0) or (0, oo)").unwrap_err();
+    assert_eq!(error.kind(), ParseErrorKind::NotAtomic);
+    assert_eq!(error.position(), 9);
+}
+
+#[test]
+fn notation_whitespace() {
+    let notation = Notation {
+        separator: " ",
+        union: " ",
+        empty: "",
+        ..Notation::default()
+    };
+
+    let x = Portion::closedopen(1, 2);
+    assert_eq!("[1 2)", notation.format(&x));
+    assert_eq!(Ok(x), notation.parse("[1 2)"));
+
+    let x = Portion::inf_open(-1) | Portion::singleton(3) | Portion::openclosed(5, 8);
+    assert_eq!("(-inf -1) [3] (5 8]", notation.format_set(&x));
+    assert_eq!(Ok(x.clone()), notation.parse_set(&notation.format_set(&x)));
+
+    let x: Interval<i32> = Portion::empty();
+    assert_eq!("", notation.format(&x));
+    assert_eq!(Ok(x), notation.parse(""));
+    assert_eq!(Ok(IntervalSet::from(x)), notation.parse_set(""));
+    assert_eq!(Ok(Portion::closed(1, 4)), notation.parse(" [1 4] "));
+
+    let error = notation.parse::<i32>("[1 2) [3 4)").unwrap_err();
+    assert_eq!(error.kind(), ParseErrorKind::NotAtomic);
+    assert_eq!(error.position(), 6);
+}
@@ -0,0 +1,69 @@
+use portion_rs::*;
+
+macro_rules! boundary_tests {
+    ($($name:ident: $t:ty),*) => ($(
+        #[test]
+        fn $name() {
+            let max = <$t>::MAX;
+            let min = <$t>::MIN;
+
+            let x: Vec<$t> = Portion::closed(max - 2, max).into_iter().collect();
+            assert_eq!(x, vec![max - 2, max - 1, max]);
+
+            let x: Vec<$t> = Portion::closed(min, min + 2).into_iter().rev().collect();
+            assert_eq!(x, vec![min + 2, min + 1, min]);
+
+            let x: Vec<$t> = Portion::open(max - 2, max).into_iter().collect();
+            assert_eq!(x, vec![max - 1]);
+
+            let x: Vec<$t> = Portion::open_inf(max - 1).into_iter().collect();
+            assert_eq!(x, vec![max]);
+
+            let x: Vec<$t> = Portion::inf_open(min + 1).into_iter().rev().collect();
+            assert_eq!(x, vec![min]);
+
+            assert_eq!(Portion::open_inf(max).into_iter().next(), None);
+            assert_eq!(Portion::inf_open(min).into_iter().next(), None);
+            assert_eq!(Portion::singleton(max).into_iter().collect::<Vec<$t>>(), vec![max]);
+
+            assert_eq!(max.checked_next(), None);
+            assert_eq!(min.checked_prev(), None);
+            assert_eq!((max - 1).checked_next(), Some(max));
+            assert_eq!((min + 1).checked_prev(), Some(min));
+        }
+    )*)
+}
+
+boundary_tests!(
+    boundary_usize: usize,
+    boundary_u8: u8,
+    boundary_u16: u16,
+    boundary_u32: u32,
+    boundary_u64: u64,
+    boundary_u128: u128,
+    boundary_isize: isize,
+    boundary_i8: i8,
+    boundary_i16: i16,
+    boundary_i32: i32,
+    boundary_i64: i64,
+    boundary_i128: i128
+);
+
+#[test]
+fn full_range() {
+    assert_eq!(Portion::<u8>::all().into_iter().count(), 256);
+    assert_eq!(Portion::<i8>::all().into_iter().rev().count(), 256);
+    assert_eq!(Portion::closed(250u8, 255).into_iter().count(), 6);
+}
+
+#[test]
+fn discrete_boundary() {
+    assert_eq!("()", Portion::open_inf(u8::MAX).discrete().to_string());
+    assert_eq!("[255]", Portion::openclosed(254u8, u8::MAX).discrete().to_string());
+    assert_eq!(
+        "[0, 255]",
+        (Portion::closed(0u8, 254) | Portion::singleton(255))
+            .discrete()
+            .to_string()
+    );
+}
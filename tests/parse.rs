@@ -0,0 +1,69 @@
+use portion_rs::*;
+
+fn roundtrip(x: Interval<i32>) {
+    assert_eq!(x, x.to_string().parse().unwrap());
+}
+
+#[test]
+fn parse_roundtrip() {
+    roundtrip(Portion::open(2, 4));
+    roundtrip(Portion::closed(-3, 6));
+    roundtrip(Portion::singleton(5));
+    roundtrip(Portion::empty());
+    roundtrip(Portion::openclosed(1, 8));
+    roundtrip(Portion::closedopen(4, 9));
+    roundtrip(Portion::all());
+    roundtrip(Portion::inf_openclosed(5));
+    roundtrip(Portion::closedopen_inf(3));
+}
+
+#[test]
+fn parse_set() {
+    let x = Portion::closed(1, 2) | Portion::open_inf(4) | Portion::singleton(-1);
+    assert_eq!(x, x.to_string().parse().unwrap());
+
+    let x: IntervalSet<i32> = "()".parse().unwrap();
+    assert!(x.empty());
+
+    let x: IntervalSet<i32> = "[1, 3] | [2, 5)".parse().unwrap();
+    assert_eq!("[1, 5)", x.to_string());
+}
+
+#[test]
+fn parse_whitespace() {
+    let x: Interval<i32> = " ( 1 ,3 ] ".parse().unwrap();
+    assert_eq!(x, Portion::openclosed(1, 3));
+
+    let x: Interval<OrderedFloat<f64>> = "[0.5, +inf)".parse().unwrap();
+    assert_eq!(x, Portion::closedopen_inf(OrderedFloat(0.5)));
+}
+
+#[test]
+fn parse_errors() {
+    let error = "[1, 2".parse::<Interval<i32>>().unwrap_err();
+    assert_eq!(error.kind(), ParseErrorKind::UnexpectedEnd);
+    assert_eq!(error.position(), 5);
+
+    let error = "{1, 2}".parse::<Interval<i32>>().unwrap_err();
+    assert_eq!(error.kind(), ParseErrorKind::UnexpectedChar('{'));
+    assert_eq!(error.position(), 0);
+
+    let error = "[1, x]".parse::<Interval<i32>>().unwrap_err();
+    assert_eq!(error.kind(), ParseErrorKind::InvalidValue);
+    assert_eq!(error.position(), 4);
+    assert_eq!("invalid value at position 4", error.to_string());
+
+    let error = "[-inf, 2]".parse::<Interval<i32>>().unwrap_err();
+    assert_eq!(error.kind(), ParseErrorKind::ClosedInfinity);
+    assert_eq!(error.position(), 1);
+
+    let error = "(5)".parse::<Interval<i32>>().unwrap_err();
+    assert_eq!(error.kind(), ParseErrorKind::UnexpectedChar(')'));
+
+    let error = "[1, 2] | [4, 5]".parse::<Interval<i32>>().unwrap_err();
+    assert_eq!(error.kind(), ParseErrorKind::NotAtomic);
+    assert_eq!(error.position(), 7);
+
+    let error = "[1, 2] [4, 5]".parse::<IntervalSet<i32>>().unwrap_err();
+    assert_eq!(error.kind(), ParseErrorKind::UnexpectedChar('['));
+}
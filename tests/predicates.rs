@@ -0,0 +1,94 @@
+use portion_rs::*;
+
+#[test]
+fn overlaps() {
+    assert!(Portion::closed(1, 3).overlaps(&Portion::closed(3, 5)));
+    assert!(!Portion::closedopen(1, 3).overlaps(&Portion::closed(3, 5)));
+    assert!(!Portion::closed(1, 3).overlaps(&Portion::open(3, 5)));
+    assert!(Portion::singleton(4).overlaps(&Portion::open(3, 5)));
+    assert!(Portion::all().overlaps(&Portion::singleton(0)));
+    assert!(!Portion::closed(1, 3).overlaps(&Portion::empty()));
+}
+
+#[test]
+fn adjacent() {
+    assert!(Portion::closedopen(1, 2).adjacent(&Portion::closed(2, 3)));
+    assert!(Portion::closed(2, 3).adjacent(&Portion::closedopen(1, 2)));
+    assert!(Portion::singleton(2).adjacent(&Portion::openclosed(2, 3)));
+    assert!(Portion::inf_open(0).adjacent(&Portion::closedopen_inf(0)));
+    assert!(!Portion::closed(1, 2).adjacent(&Portion::closed(2, 3)));
+    assert!(!Portion::closedopen(1, 2).adjacent(&Portion::open(2, 3)));
+    assert!(!Portion::closed(1, 2).adjacent(&Portion::closed(3, 4)));
+    assert!(!Portion::closed(1, 2).adjacent(&Portion::empty()));
+}
+
+#[test]
+fn before_after() {
+    assert!(Portion::closedopen(1, 2).is_before(&Portion::closed(2, 3)));
+    assert!(Portion::closed(1, 2).is_before(&Portion::open(2, 3)));
+    assert!(!Portion::closed(1, 2).is_before(&Portion::closed(2, 3)));
+    assert!(!Portion::closed(1, 5).is_before(&Portion::closed(2, 3)));
+    assert!(Portion::open_inf(3).is_after(&Portion::inf_openclosed(3)));
+    assert!(!Portion::closed(1, 2).is_before(&Portion::empty()));
+}
+
+#[test]
+fn subset() {
+    assert!(Portion::open(1, 2).is_subset(&Portion::closed(1, 2)));
+    assert!(!Portion::closed(1, 2).is_subset(&Portion::open(1, 2)));
+    assert!(Portion::closed(1, 2).is_superset(&Portion::singleton(2)));
+    assert!(Portion::empty().is_subset(&Portion::singleton(2)));
+
+    let x = Portion::closed(1, 2) | Portion::closed(5, 6);
+    let y = Portion::closed(0, 3) | Portion::open_inf(4);
+    assert!(x.is_subset(&y));
+    assert!(y.is_superset(&x));
+    assert!(!y.is_subset(&x));
+    assert!(x.overlaps(&y));
+    assert!(!x.overlaps(&(Portion::open(2, 5) | Portion::open(6, 7))));
+}
+
+#[test]
+fn union_adjacent() {
+    let x = Portion::closedopen(1, 2);
+    let y = Portion::closed(2, 3);
+    assert_eq!("[1, 3]", (x | y).to_string());
+
+    let x = Portion::closed(4, 5);
+    let y = Portion::open(2, 4);
+    assert_eq!("(2, 5]", (x | y).to_string());
+}
+
+#[test]
+fn entirely_left_right() {
+    assert!(Portion::closedopen(1, 2).entirely_left_of(Portion::closed(2, 3)));
+    assert!(Portion::closed(1, 2).entirely_left_of(Portion::open(2, 3)));
+    assert!(!Portion::closed(1, 2).entirely_left_of(Portion::closed(2, 3)));
+    assert!(Portion::closedopen(1, 2).entirely_left_of(2));
+    assert!(!Portion::closed(1, 2).entirely_left_of(2));
+    assert!(Portion::singleton(1).entirely_left_of(2));
+    assert!(Portion::open_inf(3).entirely_right_of(3));
+    assert!(!Portion::closedopen_inf(3).entirely_right_of(3));
+    assert!(Portion::closed(3, 4).entirely_right_of(Portion::inf_open(3)));
+    assert!(!Portion::<i32>::empty().entirely_left_of(Portion::closed(1, 2)));
+    assert!(!Portion::closed(1, 2).entirely_right_of(Portion::empty()));
+}
+
+#[test]
+fn left_right_or_overlapping() {
+    assert!(Portion::closed(1, 3).left_of_or_overlapping(Portion::closed(2, 3)));
+    assert!(Portion::closedopen(1, 3).left_of_or_overlapping(Portion::closedopen(2, 3)));
+    assert!(!Portion::closed(1, 3).left_of_or_overlapping(Portion::closedopen(2, 3)));
+    assert!(Portion::closed(1, 3).left_of_or_overlapping(3));
+    assert!(!Portion::closed(1, 4).left_of_or_overlapping(3));
+    assert!(Portion::singleton(3).right_of_or_overlapping(Portion::closed(3, 4)));
+    assert!(Portion::singleton(3).right_of_or_overlapping(Portion::open(2, 4)));
+    assert!(Portion::open(3, 5).right_of_or_overlapping(Portion::closed(3, 4)));
+    assert!(!Portion::closed(3, 5).right_of_or_overlapping(Portion::open(3, 4)));
+    assert!(Portion::closed(1, 2).left_of_or_overlapping(Portion::all()));
+    assert!(!Portion::<i32>::all().right_of_or_overlapping(Portion::closed(1, 2)));
+    assert!(!Portion::<i32>::empty().left_of_or_overlapping(Portion::closed(1, 2)));
+    assert!(!Portion::<i32>::empty().right_of_or_overlapping(Portion::closed(1, 2)));
+    assert!(!Portion::closed(1, 2).left_of_or_overlapping(Portion::empty()));
+    assert!(!Portion::closed(1, 2).right_of_or_overlapping(Portion::empty()));
+}
@@ -0,0 +1,133 @@
+use portion_rs::*;
+
+fn lower(value: i32) -> Replace<i32> {
+    Replace {
+        lower: Some(Bound::Value(value)),
+        ..Replace::default()
+    }
+}
+
+#[test]
+fn replace() {
+    let x = Portion::closedopen(1, 5);
+    assert_eq!("[1, 5)", x.replace(Replace::default()).to_string());
+    assert_eq!("[3, 5)", x.replace(lower(3)).to_string());
+    assert_eq!("()", x.replace(lower(6)).to_string());
+
+    let y = x.replace(Replace {
+        left: Some(BoundKind::Open),
+        right: Some(BoundKind::Closed),
+        ..Replace::default()
+    });
+    assert_eq!("(1, 5]", y.to_string());
+
+    let y = x.replace(Replace {
+        lower: Some(Bound::Value(5)),
+        right: Some(BoundKind::Closed),
+        ..Replace::default()
+    });
+    assert_eq!("[5]", y.to_string());
+    assert!(y.singleton());
+}
+
+#[test]
+fn replace_infinite() {
+    let x = Portion::closed(1, 5).replace(Replace {
+        upper: Some(Bound::PosInf),
+        ..Replace::default()
+    });
+    assert_eq!("[1, +inf)", x.to_string());
+    assert_eq!(x, Portion::closedopen_inf(1));
+
+    let x = Portion::closed(1, 5).replace(Replace {
+        lower: Some(Bound::NegInf),
+        left: Some(BoundKind::Closed),
+        ..Replace::default()
+    });
+    assert_eq!("(-inf, 5]", x.to_string());
+}
+
+#[test]
+fn replace_widen() {
+    let widen = |replace: Replace<i32>| Replace {
+        mode: ReplaceMode::Widen,
+        ..replace
+    };
+    assert_eq!("[1, 5]", Portion::closed(1, 5).replace(widen(lower(3))).to_string());
+    assert_eq!("[0, 5]", Portion::closed(1, 5).replace(widen(lower(0))).to_string());
+
+    let closed = Replace {
+        left: Some(BoundKind::Closed),
+        ..Replace::default()
+    };
+    assert_eq!("[1, 5]", Portion::openclosed(1, 5).replace(widen(closed)).to_string());
+
+    let open = Replace {
+        right: Some(BoundKind::Open),
+        ..Replace::default()
+    };
+    assert_eq!("[1, 5]", Portion::closed(1, 5).replace(widen(open)).to_string());
+
+    let both = Replace {
+        lower: Some(Bound::Value(0)),
+        upper: Some(Bound::Value(3)),
+        ..Replace::default()
+    };
+    assert_eq!("[0, 5]", Portion::closed(1, 5).replace(widen(both)).to_string());
+    assert_eq!("(0, 3)", Portion::<i32>::empty().replace(widen(both)).to_string());
+}
+
+#[test]
+fn replace_narrow() {
+    let narrow = |replace: Replace<i32>| Replace {
+        mode: ReplaceMode::Narrow,
+        ..replace
+    };
+    assert_eq!("[3, 5]", Portion::closed(1, 5).replace(narrow(lower(3))).to_string());
+    assert_eq!("[1, 5]", Portion::closed(1, 5).replace(narrow(lower(0))).to_string());
+
+    let open = Replace {
+        left: Some(BoundKind::Open),
+        right: Some(BoundKind::Open),
+        ..Replace::default()
+    };
+    assert_eq!("(1, 5)", Portion::closed(1, 5).replace(narrow(open)).to_string());
+
+    let both = Replace {
+        lower: Some(Bound::Value(0)),
+        upper: Some(Bound::Value(3)),
+        ..Replace::default()
+    };
+    assert_eq!("[1, 3]", Portion::closed(1, 5).replace(narrow(both)).to_string());
+    assert_eq!("()", Portion::<i32>::empty().replace(narrow(both)).to_string());
+}
+
+#[test]
+fn apply() {
+    let x = Portion::closed(1, 2) | Portion::closed(4, 5) | Portion::open_inf(8);
+    assert_eq!("[2, 3] | [5, 6] | (9, +inf)", x.apply(|x| x + 1).to_string());
+
+    let y = x.apply(|x| {
+        x.replace(Replace {
+            upper: Some(Bound::Value(4)),
+            ..Replace::default()
+        })
+    });
+    assert_eq!("[1, 4]", y.to_string());
+
+    assert_eq!("[1, 2] | [4, 5] | (8, +inf)", x.apply(|x| x | (x + 10)).to_string());
+}
+
+#[test]
+fn replace_empty() {
+    let x = Portion::closed(5, 1);
+    assert_eq!(x, Portion::empty());
+    assert_eq!(x.replace(lower(0)), Portion::<i32>::empty().replace(lower(0)));
+    assert_eq!("()", x.replace(lower(0)).to_string());
+
+    let upper = Replace {
+        upper: Some(Bound::Value(3)),
+        ..Replace::default()
+    };
+    assert_eq!("()", Portion::open(4, 4).replace(upper).to_string());
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "serde")]
+
+use portion_rs::*;
+use serde::{Deserialize, Serialize};
+
+fn json<T>(value: &T) -> String
+where
+    T: Serialize + for<'de> Deserialize<'de> + PartialEq + std::fmt::Debug,
+{
+    let s = serde_json::to_string(value).unwrap();
+    assert_eq!(value, &serde_json::from_str::<T>(&s).unwrap());
+
+    let bytes = bincode::serialize(value).unwrap();
+    assert_eq!(value, &bincode::deserialize::<T>(&bytes).unwrap());
+    s
+}
+
+#[test]
+fn structured_interval() {
+    assert_eq!(
+        r#"{"left":"closed","lower":1,"upper":2,"right":"open"}"#,
+        json(&Portion::closedopen(1, 2))
+    );
+    assert_eq!(
+        r#"{"left":"closed","lower":3,"upper":3,"right":"closed"}"#,
+        json(&Portion::singleton(3))
+    );
+    assert_eq!(
+        r#"{"left":"open","lower":null,"upper":5,"right":"closed"}"#,
+        json(&Portion::inf_openclosed(5))
+    );
+    assert_eq!("null", json::<Interval<i32>>(&Portion::empty()));
+}
+
+#[test]
+fn structured_set() {
+    let x = Portion::closed(1, 2) | Portion::open_inf(4);
+    assert_eq!(
+        r#"[{"left":"closed","lower":1,"upper":2,"right":"closed"},{"left":"open","lower":4,"upper":null,"right":"open"}]"#,
+        json(&x)
+    );
+    assert_eq!("[]", json::<IntervalSet<i32>>(&Portion::empty().into()));
+}
+
+#[test]
+fn structured_errors() {
+    let x = serde_json::from_str::<Interval<i32>>(r#"{"left":"closed","lower":null,"upper":5,"right":"open"}"#);
+    assert!(x.is_err());
+
+    let x = serde_json::from_str::<Interval<i32>>(r#"{"left":"half","lower":1,"upper":5,"right":"open"}"#);
+    assert!(x.is_err());
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Compact {
+    #[serde(with = "portion_rs::serialization::compact")]
+    interval: Interval<i32>,
+    #[serde(with = "portion_rs::serialization::compact")]
+    set: IntervalSet<i32>,
+}
+
+#[test]
+fn compact() {
+    let x = Compact {
+        interval: Portion::inf_open(3),
+        set: Portion::singleton(1) | Portion::closedopen(4, 6),
+    };
+    assert_eq!(r#"{"interval":"(-inf, 3)","set":"[1] | [4, 6)"}"#, json(&x));
+
+    let x = Compact {
+        interval: Portion::empty(),
+        set: Portion::empty().into(),
+    };
+    assert_eq!(r#"{"interval":"()","set":"()"}"#, json(&x));
+
+    let x = serde_json::from_str::<Compact>(r#"{"interval":"[1, 2","set":"()"}"#);
+    assert!(x
+        .unwrap_err()
+        .to_string()
+        .contains("unexpected end of input at position 5"));
+}
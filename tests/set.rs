@@ -0,0 +1,81 @@
+use portion_rs::*;
+
+#[test]
+fn atomic() {
+    let x = Portion::closed(1, 2) | Portion::closed(2, 3);
+    assert!(x.atomic());
+
+    let x = Portion::closed(1, 2) | Portion::closed(4, 5);
+    assert!(!x.atomic());
+}
+
+#[test]
+fn empty() {
+    let x: IntervalSet<i32> = Portion::empty().into();
+    assert!(x.empty());
+    assert!(x.atomic());
+    assert_eq!("()", x.to_string());
+}
+
+#[test]
+fn intersection() {
+    let x = Portion::closed(1, 3) | Portion::closed(5, 8);
+    let y = Portion::open(2, 6);
+    assert_eq!("(2, 3] | [5, 6)", (x & y).to_string());
+
+    let x = Portion::closed(1, 3) | Portion::closed(5, 8);
+    let y = Portion::closed(0, 1) | Portion::closed(7, 9);
+    assert_eq!("[1] | [7, 8]", (x & y).to_string());
+}
+
+#[test]
+fn collect() {
+    let x: IntervalSet<i32> = vec![Portion::closed(6, 7), Portion::singleton(1), Portion::closedopen(2, 6)]
+        .into_iter()
+        .collect();
+    assert_eq!("[1] | [2, 7]", x.to_string());
+}
+
+#[test]
+fn pieces() {
+    let x = Portion::closed(1, 2) | Portion::open(4, 5) | Portion::closedopen_inf(8);
+    assert_eq!(x.len(), 3);
+    assert!(!x.is_empty());
+    assert_eq!("(4, 5)", x[1].to_string());
+    assert_eq!(Some(&Portion::closedopen_inf(8)), x.get(2));
+    assert_eq!(None, x.get(3));
+
+    let pieces: Vec<Interval<i32>> = x.iter().collect();
+    assert_eq!(
+        pieces,
+        vec![Portion::closed(1, 2), Portion::open(4, 5), Portion::closedopen_inf(8)]
+    );
+
+    let mut count = 0;
+    for piece in &x {
+        assert!(!piece.empty());
+        count += 1;
+    }
+    assert_eq!(count, 3);
+
+    let last = x.into_iter().last();
+    assert_eq!(last, Some(Portion::closedopen_inf(8)));
+
+    let x: IntervalSet<i32> = Portion::empty().into();
+    assert_eq!(x.len(), 0);
+    assert!(x.is_empty());
+    assert_eq!(x.iter().next(), None);
+}
+
+#[test]
+fn values() {
+    let x = Portion::closed(1, 3) | Portion::open(5, 8) | Portion::singleton(10);
+    assert_eq!(x.values().collect::<Vec<u8>>(), vec![1, 2, 3, 6, 7, 10]);
+    assert_eq!(x.values().rev().collect::<Vec<u8>>(), vec![10, 7, 6, 3, 2, 1]);
+
+    let x = Portion::open(1, 2) | Portion::open(2, 3);
+    assert_eq!(x.values().count(), 0);
+
+    let x = Portion::inf_openclosed(1u8) | Portion::open_inf(253);
+    assert_eq!(x.values().collect::<Vec<u8>>(), vec![0, 1, 254, 255]);
+}
@@ -0,0 +1,109 @@
+use portion_rs::*;
+
+// Small deterministic generator, so the tree can be checked against a plain list.
+fn intervals(count: usize) -> Vec<Interval<i32>> {
+    let mut seed: u32 = 12345;
+    let mut next = move || {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        (seed >> 16) % 100
+    };
+
+    (0..count)
+        .map(|_| {
+            let lower = next() as i32;
+            let upper = lower + (next() % 10) as i32;
+            match next() % 5 {
+                0 => Portion::open(lower, upper),
+                1 => Portion::closed(lower, upper),
+                2 => Portion::openclosed(lower, upper),
+                3 => Portion::closedopen(lower, upper),
+                _ => Portion::singleton(lower),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn tree_containing() {
+    let list = intervals(500);
+    let tree: IntervalTree<i32, usize> = list.iter().copied().zip(0..).collect();
+    assert_eq!(tree.len(), 500);
+
+    for point in -1..111 {
+        let mut expected: Vec<usize> = (0..list.len()).filter(|&i| list[i].contains(&point)).collect();
+        let mut found: Vec<usize> = tree.containing(&point).into_iter().map(|(_, i)| *i).collect();
+        expected.sort_unstable();
+        found.sort_unstable();
+        assert_eq!(expected, found);
+    }
+}
+
+#[test]
+fn tree_overlapping() {
+    let list = intervals(500);
+    let tree: IntervalTree<i32, usize> = list.iter().copied().zip(0..).collect();
+
+    for query in intervals(50)
+        .into_iter()
+        .chain(vec![Portion::inf_open(3), Portion::empty()])
+    {
+        let mut expected: Vec<usize> = (0..list.len()).filter(|&i| !(list[i] & query).empty()).collect();
+        let mut found: Vec<usize> = tree.overlapping(&query).into_iter().map(|(_, i)| *i).collect();
+        expected.sort_unstable();
+        found.sort_unstable();
+        assert_eq!(expected, found);
+    }
+}
+
+#[test]
+fn tree_bounds() {
+    let mut tree = IntervalTree::new();
+    tree.insert(Portion::closedopen(1, 2), 'a');
+    tree.insert(Portion::closed(2, 3), 'b');
+    tree.insert(Portion::open(3, 4), 'c');
+
+    let found: Vec<char> = tree.containing(&2).into_iter().map(|(_, x)| *x).collect();
+    assert_eq!(found, vec!['b']);
+    let found: Vec<char> = tree.containing(&3).into_iter().map(|(_, x)| *x).collect();
+    assert_eq!(found, vec!['b']);
+    let found: Vec<char> = tree
+        .overlapping(&Portion::singleton(3))
+        .into_iter()
+        .map(|(_, x)| *x)
+        .collect();
+    assert_eq!(found, vec!['b']);
+    let found: Vec<char> = tree
+        .overlapping(&Portion::open(2, 3))
+        .into_iter()
+        .map(|(_, x)| *x)
+        .collect();
+    assert_eq!(found, vec!['b']);
+}
+
+#[test]
+fn tree_remove_iter() {
+    let list = intervals(200);
+    let mut tree: IntervalTree<i32, usize> = list.iter().copied().zip(0..).collect();
+
+    let mut sorted = list.clone();
+    sorted.sort();
+    let keys: Vec<Interval<i32>> = tree.iter().map(|(x, _)| x).collect();
+    assert_eq!(keys, sorted);
+
+    for (i, interval) in list.iter().enumerate().step_by(2) {
+        assert!(tree.remove(interval).is_some());
+        assert_eq!(tree.len(), 200 - i / 2 - 1);
+    }
+    assert_eq!(tree.remove(&Portion::closed(1000, 1001)), None);
+
+    for point in 0..110 {
+        let expected = (1..list.len()).step_by(2).filter(|&i| list[i].contains(&point)).count();
+        assert_eq!(tree.containing(&point).len(), expected);
+    }
+
+    for interval in list.iter().skip(1).step_by(2) {
+        assert!(tree.remove(interval).is_some());
+    }
+    assert!(tree.is_empty());
+    assert_eq!(tree.iter().count(), 0);
+}
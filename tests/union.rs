@@ -2,8 +2,8 @@ use portion_rs::*;
 
 #[test]
 fn union_empty() {
-    let x = Portion::closed(1, 2);
-    let y = Portion::closed(4, 5);
+    let x: Interval<i32> = Portion::empty();
+    let y = Portion::empty();
     assert_eq!("()", (x | y).to_string());
 
     let x = Portion::empty();
@@ -15,6 +15,10 @@ fn union_empty() {
 fn union_open() {
     let x = Portion::open(2, 4);
     let y = Portion::open(4, 6);
+    assert_eq!("(2, 4) | (4, 6)", (x | y).to_string());
+
+    let x = Portion::open(2, 4);
+    let y = Portion::closedopen(4, 6);
     assert_eq!("(2, 6)", (x | y).to_string());
 }
 
@@ -41,7 +45,22 @@ fn union_singleton() {
 
     let x = Portion::singleton(1);
     let y = Portion::singleton(2);
-    assert_eq!("[1, 2]", (x | y).to_string());
+    assert_eq!("[1] | [2]", (x | y).to_string());
+}
+
+#[test]
+fn union_disjoint() {
+    let x = Portion::closed(1, 2);
+    let y = Portion::closed(4, 5);
+    assert_eq!("[1, 2] | [4, 5]", (x | y).to_string());
+
+    let x = Portion::closed(4, 5);
+    let y = Portion::closed(1, 2);
+    assert_eq!("[1, 2] | [4, 5]", (x | y).to_string());
+
+    let x = Portion::closed(1, 2) | Portion::closed(4, 5);
+    let y = Portion::closed(2, 4);
+    assert_eq!("[1, 5]", (x | y).to_string());
 }
 
 #[test]
@@ -58,3 +77,18 @@ fn union_variety() {
     let y = Portion::closed(3, 8);
     assert_eq!("(2, 8]", (x | y).to_string());
 }
+
+#[test]
+fn union_infinite() {
+    let x = Portion::inf_open(3);
+    let y = Portion::closedopen_inf(3);
+    assert_eq!("(-inf, +inf)", (x | y).to_string());
+
+    let x = Portion::inf_open(3);
+    let y = Portion::open_inf(3);
+    assert_eq!("(-inf, 3) | (3, +inf)", (x | y).to_string());
+
+    let x = Portion::closed(1, 4);
+    let y = Portion::open_inf(2);
+    assert_eq!("[1, +inf)", (x | y).to_string());
+}